@@ -0,0 +1,150 @@
+use crate::set_param;
+
+/// Generates the `sharp`, `usm` and `usmrad` setters, which are shared by
+/// `ImgixAdjust` and `ImgixDetail`. The builder must have a `set` method.
+macro_rules! sharpening_setters {
+    () => {
+        /// Sharpens the image using luminance. Valid values are in the range
+        /// of 0 - 100.
+        pub fn sharp(&mut self, val: i32) -> &mut Self {
+            self.set("sharp", val.to_string())
+        }
+
+        /// Sharpens the image details using an unsharp mask. Valid values are
+        /// in the range of -100 - 100.
+        pub fn usm(&mut self, val: i32) -> &mut Self {
+            self.set("usm", val.to_string())
+        }
+
+        /// Sets the radius of the unsharp mask applied by `usm`. Must be a
+        /// positive number.
+        pub fn usmrad(&mut self, val: f64) -> &mut Self {
+            self.set("usmrad", val.to_string())
+        }
+    };
+}
+
+pub(crate) use sharpening_setters;
+
+/// Builder to represent Imgix's adjustment parameters, such as `bri` and
+/// `con`. Begin constructing the parameters by calling `build()`.
+///
+/// Values are checked against the ranges documented by Imgix when the URL is
+/// completed with `try_finish()`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixAdjust, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .adjust(ImgixAdjust::build().bri(20).con(-10).usmrad(2.5).finish())
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?bri=20&con=-10&usmrad=2.5");
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/adjustment) for more info.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixAdjust {
+    params: Vec<(&'static str, String)>,
+}
+
+impl ImgixAdjust {
+    /// Starts building the adjustment parameters. Returns an `ImgixAdjust` to
+    /// specify the adjustments to apply.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the adjustment parameters and returns the
+    /// final `ImgixAdjust` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> &[(&'static str, String)] {
+        &self.params
+    }
+
+    pub(crate) fn set(&mut self, key: &'static str, val: String) -> &mut Self {
+        set_param(&mut self.params, key, val);
+        self
+    }
+
+    /// Adjusts the brightness of the image. Valid values are in the range of
+    /// -100 - 100.
+    pub fn bri(&mut self, val: i32) -> &mut Self {
+        self.set("bri", val.to_string())
+    }
+
+    /// Adjusts the contrast of the image. Valid values are in the range of
+    /// -100 - 100.
+    pub fn con(&mut self, val: i32) -> &mut Self {
+        self.set("con", val.to_string())
+    }
+
+    /// Adjusts the exposure of the image. Valid values are in the range of
+    /// -100 - 100.
+    pub fn exp(&mut self, val: i32) -> &mut Self {
+        self.set("exp", val.to_string())
+    }
+
+    /// Adjusts the gamma of the image. Valid values are in the range of
+    /// -100 - 100.
+    pub fn gam(&mut self, val: i32) -> &mut Self {
+        self.set("gam", val.to_string())
+    }
+
+    /// Adjusts the highlight tonal mapping of the image. Valid values are in
+    /// the range of -100 - 0.
+    pub fn high(&mut self, val: i32) -> &mut Self {
+        self.set("high", val.to_string())
+    }
+
+    /// Changes the hue of the image by rotating its colors by `val` degrees.
+    /// Valid values are in the range of -359 - 359.
+    pub fn hue(&mut self, val: i32) -> &mut Self {
+        self.set("hue", val.to_string())
+    }
+
+    /// Inverts all pixel colors and brightness values of the image.
+    pub fn invert(&mut self) -> &mut Self {
+        self.set("invert", "true".into())
+    }
+
+    /// Adjusts the saturation of the image. Valid values are in the range of
+    /// -100 - 100.
+    pub fn sat(&mut self, val: i32) -> &mut Self {
+        self.set("sat", val.to_string())
+    }
+
+    /// Adjusts the shadow tonal mapping of the image. Valid values are in the
+    /// range of 0 - 100.
+    pub fn shad(&mut self, val: i32) -> &mut Self {
+        self.set("shad", val.to_string())
+    }
+
+    sharpening_setters!();
+
+    /// Adjusts the vibrance of the image. Valid values are in the range of
+    /// -100 - 100.
+    pub fn vib(&mut self, val: i32) -> &mut Self {
+        self.set("vib", val.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let adjust = ImgixAdjust::build().sat(10).invert().sat(-10).finish();
+
+        assert_eq!(
+            adjust.params(),
+            &[("sat", "-10".to_string()), ("invert", "true".to_string())]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
+/// Builder to represent alignment parameters such as `txt-align`. Begin
+/// constructing the parameter by calling `build()`.
+///
+/// At most one vertical (`top`, `middle`, `bottom`) and one horizontal (`left`,
+/// `center`, `right`) alignment should be given. Conflicting alignments are
+/// reported by `try_finish()`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixAlign, ImgixText, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .txt(
+///         ImgixText::build()
+///             .text("Hello")
+///             .align(ImgixAlign::build().bottom().right().finish())
+///             .finish(),
+///     )
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?txt=Hello&txt-align=bottom,right");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixAlign {
+    opts: Vec<&'static str>,
+}
+
+impl fmt::Display for ImgixAlign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.opts.join(","))
+    }
+}
+
+impl FromStr for ImgixAlign {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut align = Self::build();
+
+        for opt in s.split(',') {
+            match opt {
+                "top" => align.top(),
+                "middle" => align.middle(),
+                "bottom" => align.bottom(),
+                "left" => align.left(),
+                "center" => align.center(),
+                "right" => align.right(),
+                _ => return Err(ImgixError::invalid_value("align", s)),
+            };
+        }
+
+        Ok(align)
+    }
+}
+
+impl ImgixAlign {
+    /// Starts building the alignment. Returns an `ImgixAlign` to specify
+    /// options to pass to the parameter.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the alignment and returns the final
+    /// `ImgixAlign` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// Align to the top.
+    pub fn top(&mut self) -> &mut Self {
+        self.opts.push("top");
+        self
+    }
+
+    /// Align to the vertical middle.
+    pub fn middle(&mut self) -> &mut Self {
+        self.opts.push("middle");
+        self
+    }
+
+    /// Align to the bottom.
+    pub fn bottom(&mut self) -> &mut Self {
+        self.opts.push("bottom");
+        self
+    }
+
+    /// Align to the left.
+    pub fn left(&mut self) -> &mut Self {
+        self.opts.push("left");
+        self
+    }
+
+    /// Align to the horizontal center.
+    pub fn center(&mut self) -> &mut Self {
+        self.opts.push("center");
+        self
+    }
+
+    /// Align to the right.
+    pub fn right(&mut self) -> &mut Self {
+        self.opts.push("right");
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let str_params = ImgixAlign::build().middle().center().finish().to_string();
+
+        assert_eq!(str_params, "middle,center");
+        assert_eq!(
+            "middle,center".parse(),
+            Ok(ImgixAlign::build().middle().center().finish())
+        );
+    }
+}
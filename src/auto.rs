@@ -1,3 +1,7 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
 /// Builder to represent the `auto` URL parameter. Begin constructing the
 /// parameter by calling `build()`.
 ///
@@ -5,24 +9,44 @@
 /// ```
 /// use rs_imgix::{ImgixUrl, ImgixAuto};
 ///
-/// let url = ImgixUrl::build("https://foo.com")
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
 ///     .auto(ImgixAuto::build().compress().redeye().finish())
 ///     .finish();
 ///
-/// assert_eq!(url, "https://foo.com/?auto=compress,redeye");
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?auto=compress,redeye");
 /// ```
-#[derive(Clone, Debug, Default)]
-pub struct ImgixAuto<'a> {
-    opts: Vec<&'a str>,
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixAuto {
+    opts: Vec<&'static str>,
+}
+
+impl fmt::Display for ImgixAuto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.opts.join(","))
+    }
 }
 
-impl<'a> ToString for ImgixAuto<'a> {
-    fn to_string(&self) -> String {
-        self.opts.join(",")
+impl FromStr for ImgixAuto {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut auto = Self::build();
+
+        for opt in s.split(',') {
+            match opt {
+                "compress" => auto.compress(),
+                "enhance" => auto.enhance(),
+                "format" => auto.format(),
+                "redeye" => auto.redeye(),
+                _ => return Err(ImgixError::invalid_value("auto", s)),
+            };
+        }
+
+        Ok(auto)
     }
 }
 
-impl<'a> ImgixAuto<'a> {
+impl ImgixAuto {
     /// Starts building the `auto` parameter. Returns an `ImgixAutoBuilder` to
     /// specify options to pass to `auto`.
     pub fn build() -> Self {
@@ -0,0 +1,271 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    encoding, error::ImgixError, set_param, ImgixAlign, ImgixColor, ImgixCrop, ImgixFit,
+    ImgixUrlBuilder,
+};
+
+/// The `blend-mode` parameter controls how the blend source is composited
+/// over the image.
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/blending/blend-mode) for
+/// more info.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixBlendMode {
+    /// **Default**. Places the blend source over the image.
+    Normal,
+
+    /// Keeps the darker of each pixel.
+    Darken,
+
+    /// Multiplies the colors, darkening the image.
+    Multiply,
+
+    /// Darkens the image by increasing the contrast.
+    Burn,
+
+    /// Inverts, multiplies and inverts the colors, lightening the image.
+    Screen,
+
+    /// Keeps the lighter of each pixel.
+    Lighten,
+
+    /// Lightens the image by decreasing the contrast.
+    Dodge,
+
+    /// Multiplies or screens the colors, depending on the image.
+    Overlay,
+
+    /// Darkens or lightens the colors, depending on the blend source.
+    SoftLight,
+
+    /// Multiplies or screens the colors, depending on the blend source.
+    HardLight,
+
+    /// Subtracts the darker of the two colors from the lighter one.
+    Difference,
+
+    /// Like `Difference`, with lower contrast.
+    Exclusion,
+
+    /// Uses the hue and saturation of the blend source with the luminosity of
+    /// the image.
+    Color,
+
+    /// Uses the hue of the blend source.
+    Hue,
+
+    /// Uses the saturation of the blend source.
+    Saturation,
+
+    /// Uses the luminosity of the blend source.
+    Luminosity,
+}
+
+impl fmt::Display for ImgixBlendMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixBlendMode::Normal => f.write_str("normal"),
+            ImgixBlendMode::Darken => f.write_str("darken"),
+            ImgixBlendMode::Multiply => f.write_str("multiply"),
+            ImgixBlendMode::Burn => f.write_str("burn"),
+            ImgixBlendMode::Screen => f.write_str("screen"),
+            ImgixBlendMode::Lighten => f.write_str("lighten"),
+            ImgixBlendMode::Dodge => f.write_str("dodge"),
+            ImgixBlendMode::Overlay => f.write_str("overlay"),
+            ImgixBlendMode::SoftLight => f.write_str("softlight"),
+            ImgixBlendMode::HardLight => f.write_str("hardlight"),
+            ImgixBlendMode::Difference => f.write_str("difference"),
+            ImgixBlendMode::Exclusion => f.write_str("exclusion"),
+            ImgixBlendMode::Color => f.write_str("color"),
+            ImgixBlendMode::Hue => f.write_str("hue"),
+            ImgixBlendMode::Saturation => f.write_str("saturation"),
+            ImgixBlendMode::Luminosity => f.write_str("luminosity"),
+        }
+    }
+}
+
+impl FromStr for ImgixBlendMode {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(ImgixBlendMode::Normal),
+            "darken" => Ok(ImgixBlendMode::Darken),
+            "multiply" => Ok(ImgixBlendMode::Multiply),
+            "burn" => Ok(ImgixBlendMode::Burn),
+            "screen" => Ok(ImgixBlendMode::Screen),
+            "lighten" => Ok(ImgixBlendMode::Lighten),
+            "dodge" => Ok(ImgixBlendMode::Dodge),
+            "overlay" => Ok(ImgixBlendMode::Overlay),
+            "softlight" => Ok(ImgixBlendMode::SoftLight),
+            "hardlight" => Ok(ImgixBlendMode::HardLight),
+            "difference" => Ok(ImgixBlendMode::Difference),
+            "exclusion" => Ok(ImgixBlendMode::Exclusion),
+            "color" => Ok(ImgixBlendMode::Color),
+            "hue" => Ok(ImgixBlendMode::Hue),
+            "saturation" => Ok(ImgixBlendMode::Saturation),
+            "luminosity" => Ok(ImgixBlendMode::Luminosity),
+            _ => Err(ImgixError::invalid_value("blend-mode", s)),
+        }
+    }
+}
+
+/// Builder to represent the `blend` parameter and the `blend-*` parameters
+/// that control it. Begin constructing the parameters by calling `build()`.
+///
+/// The blend source is either a color, or an image given as a plain URL or
+/// another `ImgixUrlBuilder`. Images are sent base64 encoded with `blend64`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixBlend, ImgixBlendMode, ImgixColor, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .blend(
+///         ImgixBlend::build()
+///             .color(ImgixColor::hex("ff0000").unwrap())
+///             .mode(ImgixBlendMode::Multiply)
+///             .alpha(40)
+///             .finish(),
+///     )
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/cat.jpg?blend=ff0000&blend-mode=multiply&blend-alpha=40"
+/// );
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/blending) for more info.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixBlend {
+    params: Vec<(&'static str, String)>,
+}
+
+impl ImgixBlend {
+    /// Starts building the blend parameters. Returns an `ImgixBlend` to specify
+    /// the blend source and how it is composited.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the blend parameters and returns the final
+    /// `ImgixBlend` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> &[(&'static str, String)] {
+        &self.params
+    }
+
+    pub(crate) fn set(&mut self, key: &'static str, val: String) -> &mut Self {
+        set_param(&mut self.params, key, val);
+        self
+    }
+
+    /// Uses a solid `color` as the blend source.
+    pub fn color(&mut self, color: ImgixColor) -> &mut Self {
+        self.params.retain(|(k, _)| *k != "blend64");
+        self.set("blend", color.to_string())
+    }
+
+    /// Uses the image at `url` as the blend source.
+    pub fn url(&mut self, url: &str) -> &mut Self {
+        self.params.retain(|(k, _)| *k != "blend");
+        self.set("blend64", encoding::base64(url))
+    }
+
+    /// Uses the Imgix image built by `image` as the blend source. The nested
+    /// URL is signed if `image` has a secure token.
+    pub fn image(&mut self, image: &ImgixUrlBuilder) -> &mut Self {
+        self.url(&image.finish())
+    }
+
+    /// How the blend source is composited over the image.
+    pub fn mode(&mut self, mode: ImgixBlendMode) -> &mut Self {
+        self.set("blend-mode", mode.to_string())
+    }
+
+    /// The alignment of the blend source within the image.
+    pub fn align(&mut self, align: ImgixAlign) -> &mut Self {
+        self.set("blend-align", align.to_string())
+    }
+
+    /// The opacity of the blend source. Valid values are in the range of
+    /// 0 - 100.
+    pub fn alpha(&mut self, val: i32) -> &mut Self {
+        self.set("blend-alpha", val.to_string())
+    }
+
+    /// The horizontal position of the blend source, in pixels.
+    pub fn x(&mut self, val: i32) -> &mut Self {
+        self.set("blend-x", val.to_string())
+    }
+
+    /// The vertical position of the blend source, in pixels.
+    pub fn y(&mut self, val: i32) -> &mut Self {
+        self.set("blend-y", val.to_string())
+    }
+
+    /// The width of the blend source, in pixels.
+    pub fn w(&mut self, val: i32) -> &mut Self {
+        self.set("blend-w", val.to_string())
+    }
+
+    /// The height of the blend source, in pixels.
+    pub fn h(&mut self, val: i32) -> &mut Self {
+        self.set("blend-h", val.to_string())
+    }
+
+    /// How the blend source is fit to `w()` and `h()`. Only `Clamp`, `Clip`,
+    /// `Crop`, `Max` and `Scale` are valid.
+    pub fn fit(&mut self, fit: ImgixFit) -> &mut Self {
+        self.set("blend-fit", fit.to_string())
+    }
+
+    /// How the blend source is cropped when `fit()` is `Crop`. Only `top`,
+    /// `bottom`, `left`, `right` and `faces` are valid.
+    pub fn crop(&mut self, crop: ImgixCrop) -> &mut Self {
+        self.set("blend-crop", crop.to_string())
+    }
+
+    /// The padding between the blend source and the edges of the image, in
+    /// pixels.
+    pub fn pad(&mut self, val: i32) -> &mut Self {
+        self.set("blend-pad", val.to_string())
+    }
+
+    /// Sizes the blend source to match the dimensions of the image.
+    pub fn size_inherit(&mut self) -> &mut Self {
+        self.set("blend-size", "inherit".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let blend = ImgixBlend::build()
+            .color(ImgixColor::rgb(0, 0, 0))
+            .url("https://foo.com/texture.png")
+            .size_inherit()
+            .finish();
+
+        assert_eq!(
+            blend.params(),
+            &[
+                (
+                    "blend64",
+                    "aHR0cHM6Ly9mb28uY29tL3RleHR1cmUucG5n".to_string()
+                ),
+                ("blend-size", "inherit".to_string())
+            ]
+        );
+        assert_eq!("softlight".parse(), Ok(ImgixBlendMode::SoftLight));
+    }
+}
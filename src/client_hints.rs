@@ -1,3 +1,7 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
 /// Builder to represent the `ch` URL parameter. Begin constructing the
 /// parameter by calling `build()`.
 ///
@@ -5,24 +9,43 @@
 /// ```
 /// use rs_imgix::{ImgixUrl, ImgixClientHints};
 ///
-/// let url = ImgixUrl::build("https://foo.com")
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
 ///     .ch(ImgixClientHints::build().dpr().width().finish())
 ///     .finish();
 ///
-/// assert_eq!(url, "https://foo.com/?ch=dpr,width");
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?ch=dpr,width");
 /// ```
-#[derive(Clone, Debug, Default)]
-pub struct ImgixClientHints<'a> {
-    opts: Vec<&'a str>,
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixClientHints {
+    opts: Vec<&'static str>,
+}
+
+impl fmt::Display for ImgixClientHints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.opts.join(","))
+    }
 }
 
-impl<'a> ToString for ImgixClientHints<'a> {
-    fn to_string(&self) -> String {
-        self.opts.join(",")
+impl FromStr for ImgixClientHints {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ch = Self::build();
+
+        for opt in s.split(',') {
+            match opt {
+                "width" => ch.width(),
+                "dpr" => ch.dpr(),
+                "save-data" => ch.save_data(),
+                _ => return Err(ImgixError::invalid_value("ch", s)),
+            };
+        }
+
+        Ok(ch)
     }
 }
 
-impl<'a> ImgixClientHints<'a> {
+impl ImgixClientHints {
     /// Starts building the `ch` parameter. Returns an `ImgixClientHintsBuilder`
     /// to specify options to pass to `ch`.
     pub fn build() -> Self {
@@ -0,0 +1,260 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
+/// CSS color keywords accepted by Imgix.
+const NAMED: &[&str] = &[
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Repr {
+    Hex(String),
+    Named(&'static str),
+    Argb(u8, u8, u8, u8),
+}
+
+/// A color value for parameters such as `bg`. Can be constructed from a hex
+/// string, a CSS color keyword, or RGBA components.
+///
+/// Hex strings may have 3, 4, 6 or 8 digits, with or without a leading `#`.
+/// As Imgix defines them, 3 and 6 digit values are RGB, while 4 and 8 digit
+/// values are ARGB, with the alpha channel first.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixColor, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .bg(ImgixColor::hex("#80FF0000").unwrap())
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?bg=80FF0000");
+/// assert_eq!(ImgixColor::rgba(255, 0, 0, 128).to_string(), "80FF0000");
+/// assert_eq!(ImgixColor::named("Red").unwrap().to_string(), "red");
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/color-values) for more
+/// info.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImgixColor(Repr);
+
+impl ImgixColor {
+    /// Creates a color from a 3, 4, 6 or 8 digit hex string, optionally
+    /// prefixed with `#`.
+    pub fn hex(val: &str) -> Result<Self, ImgixError> {
+        let digits = val.trim_start_matches('#');
+        let valid =
+            matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit());
+
+        if valid {
+            Ok(ImgixColor(Repr::Hex(digits.into())))
+        } else {
+            Err(ImgixError::invalid_value("color", val))
+        }
+    }
+
+    /// Creates a color from a CSS color keyword, such as `red` or
+    /// `rebeccapurple`.
+    pub fn named(val: &str) -> Result<Self, ImgixError> {
+        let lower = val.to_ascii_lowercase();
+
+        NAMED
+            .iter()
+            .find(|name| **name == lower)
+            .map(|name| ImgixColor(Repr::Named(name)))
+            .ok_or_else(|| ImgixError::invalid_value("color", val))
+    }
+
+    /// Creates an opaque color from RGB components.
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 255)
+    }
+
+    /// Creates a color from RGBA components. Serialized as 6 digit RGB when
+    /// fully opaque, and as 8 digit ARGB otherwise.
+    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        ImgixColor(Repr::Argb(a, r, g, b))
+    }
+}
+
+impl fmt::Display for ImgixColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Repr::Hex(digits) => f.write_str(digits),
+            Repr::Named(name) => f.write_str(name),
+            Repr::Argb(255, r, g, b) => write!(f, "{:02X}{:02X}{:02X}", r, g, b),
+            Repr::Argb(a, r, g, b) => write!(f, "{:02X}{:02X}{:02X}{:02X}", a, r, g, b),
+        }
+    }
+}
+
+impl FromStr for ImgixColor {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::hex(s).or_else(|_| Self::named(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!("#fff".parse::<ImgixColor>().unwrap().to_string(), "fff");
+        assert_eq!("8fff".parse::<ImgixColor>().unwrap().to_string(), "8fff");
+        assert_eq!(ImgixColor::rgb(0, 128, 255).to_string(), "0080FF");
+        assert_eq!(ImgixColor::rgba(0, 128, 255, 0).to_string(), "000080FF");
+        assert!("#ffff0".parse::<ImgixColor>().is_err());
+        assert!("reddish".parse::<ImgixColor>().is_err());
+    }
+}
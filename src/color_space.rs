@@ -1,8 +1,12 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
 /// The `cs` parameter specifies the color space of the output image.
 ///
 /// See [Imgix docs](https://docs.imgix.com/apis/url/format/cs) for more
 /// info.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ImgixColorSpace {
     /// Sets the image to the sRGB color space, the Internet standard.
     SRGB,
@@ -19,13 +23,27 @@ pub enum ImgixColorSpace {
     Strip,
 }
 
-impl ToString for ImgixColorSpace {
-    fn to_string(&self) -> String {
+impl fmt::Display for ImgixColorSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ImgixColorSpace::SRGB => "srgb".into(),
-            ImgixColorSpace::AdobeRGB1998 => "adobergb1998".into(),
-            ImgixColorSpace::TinySRGB => "tinysrgb".into(),
-            ImgixColorSpace::Strip => "strip".into(),
+            ImgixColorSpace::SRGB => f.write_str("srgb"),
+            ImgixColorSpace::AdobeRGB1998 => f.write_str("adobergb1998"),
+            ImgixColorSpace::TinySRGB => f.write_str("tinysrgb"),
+            ImgixColorSpace::Strip => f.write_str("strip"),
+        }
+    }
+}
+
+impl FromStr for ImgixColorSpace {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "srgb" => Ok(ImgixColorSpace::SRGB),
+            "adobergb1998" => Ok(ImgixColorSpace::AdobeRGB1998),
+            "tinysrgb" => Ok(ImgixColorSpace::TinySRGB),
+            "strip" => Ok(ImgixColorSpace::Strip),
+            _ => Err(ImgixError::invalid_value("cs", s)),
         }
     }
 }
@@ -1,3 +1,7 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
 /// Crop mode controls how the image is aligned when `fit=crop` is set. The
 /// `w` and `h` parameters should also be set, so that the crop behavior is
 /// defined within specific image dimensions.
@@ -6,27 +10,51 @@
 /// ```
 /// use rs_imgix::{ImgixUrl, ImgixCrop, ImgixFit};
 ///
-/// let url = ImgixUrl::build("https://foo.com")
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
 ///     .fit(ImgixFit::Crop)
 ///     .w(400)
 ///     .h(300)
 ///     .crop(ImgixCrop::build().top().entropy().finish())
 ///     .finish();
 ///
-/// assert_eq!(url, "https://foo.com/?fit=crop&w=400&h=300&crop=top,entropy")
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?fit=crop&w=400&h=300&crop=top,entropy")
 /// ```
-#[derive(Clone, Debug, Default)]
-pub struct ImgixCrop<'a> {
-    opts: Vec<&'a str>,
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixCrop {
+    opts: Vec<&'static str>,
+}
+
+impl fmt::Display for ImgixCrop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.opts.join(","))
+    }
 }
 
-impl<'a> ToString for ImgixCrop<'a> {
-    fn to_string(&self) -> String {
-        self.opts.join(",")
+impl FromStr for ImgixCrop {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut crop = Self::build();
+
+        for opt in s.split(',') {
+            match opt {
+                "top" => crop.top(),
+                "bottom" => crop.bottom(),
+                "left" => crop.left(),
+                "right" => crop.right(),
+                "faces" => crop.faces(),
+                "focalpoint" => crop.focalpoint(),
+                "edges" => crop.edges(),
+                "entropy" => crop.entropy(),
+                _ => return Err(ImgixError::invalid_value("crop", s)),
+            };
+        }
+
+        Ok(crop)
     }
 }
 
-impl<'a> ImgixCrop<'a> {
+impl ImgixCrop {
     /// Starts building the `crop` parameter. Returns an `ImgixCropBuilder` to
     /// specify options to pass to `crop`.
     pub fn build() -> Self {
@@ -0,0 +1,86 @@
+use crate::{adjust::sharpening_setters, set_param};
+
+/// Builder to represent Imgix's noise reduction and sharpening parameters,
+/// `nr`, `nrs`, `sharp`, `usm` and `usmrad`. Begin constructing the parameters
+/// by calling `build()`.
+///
+/// The sharpening setters are the same as those of `ImgixAdjust`, which sets
+/// the same parameters, so whichever group is applied last wins.
+///
+/// Values are checked against the ranges documented by Imgix when the URL is
+/// completed with `try_finish()`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixDetail, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "night.jpg")
+///     .detail(ImgixDetail::build().nr(40).nrs(30).usm(20).usmrad(1.5).finish())
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/night.jpg?nr=40&nrs=30&usm=20&usmrad=1.5"
+/// );
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/noise-reduction) for more
+/// info.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixDetail {
+    params: Vec<(&'static str, String)>,
+}
+
+impl ImgixDetail {
+    /// Starts building the noise reduction and sharpening parameters. Returns
+    /// an `ImgixDetail` to specify the values to apply.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the parameters and returns the final
+    /// `ImgixDetail` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> &[(&'static str, String)] {
+        &self.params
+    }
+
+    pub(crate) fn set(&mut self, key: &'static str, val: String) -> &mut Self {
+        set_param(&mut self.params, key, val);
+        self
+    }
+
+    /// Reduces the noise of the image, with a higher value removing more noise
+    /// at the cost of detail. Valid values are in the range of -100 - 100.
+    pub fn nr(&mut self, val: i32) -> &mut Self {
+        self.set("nr", val.to_string())
+    }
+
+    /// Sets the sharpness threshold under which noise reduction is applied,
+    /// so that sharper areas are left untouched. Valid values are in the range
+    /// of -100 - 100.
+    pub fn nrs(&mut self, val: i32) -> &mut Self {
+        self.set("nrs", val.to_string())
+    }
+
+    sharpening_setters!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let detail = ImgixDetail::build().nr(10).sharp(20).nr(-10).finish();
+
+        assert_eq!(
+            detail.params(),
+            &[("nr", "-10".to_string()), ("sharp", "20".to_string())]
+        );
+    }
+}
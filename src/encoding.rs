@@ -0,0 +1,94 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use percent_encoding::{
+    percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC,
+};
+
+/// Characters escaped by `encodeURIComponent`. Used for fully qualified source
+/// URLs of Web Proxy sources, which are passed to Imgix as a single segment.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+/// Characters escaped in each segment of an image path. Mirrors `encodeURI`
+/// with `#`, `?`, `:` and `+` escaped as well, as Imgix's own libraries do.
+const PATH: &AsciiSet = &COMPONENT
+    .remove(b';')
+    .remove(b',')
+    .remove(b'/')
+    .remove(b'@')
+    .remove(b'&')
+    .remove(b'=')
+    .remove(b'$');
+
+/// Characters escaped in query string keys and values.
+const QUERY: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'&')
+    .add(b'+')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`');
+
+/// Percent-encodes an image path, ensuring it begins with a `/`.
+pub(crate) fn path(path: &str) -> String {
+    let path = path.trim_start_matches('/');
+
+    if path.starts_with("http://") || path.starts_with("https://") {
+        format!("/{}", utf8_percent_encode(path, COMPONENT))
+    } else {
+        format!("/{}", utf8_percent_encode(path, PATH))
+    }
+}
+
+/// Percent-encodes a single query parameter as `key=val`.
+pub(crate) fn pair(key: &str, val: &str) -> String {
+    format!(
+        "{}={}",
+        utf8_percent_encode(key, QUERY),
+        utf8_percent_encode(val, QUERY)
+    )
+}
+
+/// Encodes `s` as unpadded, URL-safe base64, for parameters such as `txt64`.
+pub(crate) fn base64(s: &str) -> String {
+    URL_SAFE_NO_PAD.encode(s)
+}
+
+/// Decodes a percent-encoded path or query component.
+pub(crate) fn decode(s: &str) -> String {
+    percent_decode_str(s).decode_utf8_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(
+            path("photos/cat dog#1?.jpg"),
+            "/photos/cat%20dog%231%3F.jpg"
+        );
+        assert_eq!(path("/ünïcode+:.jpg"), "/%C3%BCn%C3%AFcode%2B%3A.jpg");
+        assert_eq!(
+            path("https://foo.com/cat.jpg"),
+            "/https%3A%2F%2Ffoo.com%2Fcat.jpg"
+        );
+        assert_eq!(pair("txt", "100% #1"), "txt=100%25%20%231");
+        assert_eq!(pair("ar", "9:1"), "ar=9:1");
+        assert_eq!(decode("cat%20dog%231.jpg"), "cat dog#1.jpg");
+        assert_eq!(base64("Hello?>"), "SGVsbG8_Pg");
+    }
+}
@@ -0,0 +1,89 @@
+use std::{error, fmt};
+
+/// Errors that can occur when parsing or validating an Imgix URL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImgixError {
+    /// The URL could not be split into a scheme, domain and path.
+    InvalidUrl(String),
+
+    /// A parameter was given a value that Imgix does not accept.
+    InvalidValue {
+        /// Name of the parameter.
+        param: String,
+
+        /// The rejected value.
+        value: String,
+    },
+
+    /// A numeric parameter is outside of the range Imgix accepts.
+    OutOfRange {
+        /// Name of the parameter.
+        param: String,
+
+        /// The rejected value.
+        value: String,
+
+        /// Description of the accepted range, e.g. `between 0 and 100`.
+        expected: String,
+    },
+
+    /// A parameter is set without another parameter it depends on.
+    MissingParam {
+        /// Name of the parameter.
+        param: String,
+
+        /// The parameter (and value) it requires, e.g. `fit=crop`.
+        requires: String,
+    },
+
+    /// A parameter combines two values that contradict each other.
+    Conflict {
+        /// Name of the parameter.
+        param: String,
+
+        /// The first conflicting value.
+        first: String,
+
+        /// The second conflicting value.
+        second: String,
+    },
+}
+
+impl ImgixError {
+    pub(crate) fn invalid_value(param: &str, value: &str) -> Self {
+        ImgixError::InvalidValue {
+            param: param.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl fmt::Display for ImgixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixError::InvalidUrl(url) => write!(f, "`{}` is not a valid URL", url),
+            ImgixError::InvalidValue { param, value } => {
+                write!(f, "`{}` is not a valid value for `{}`", value, param)
+            }
+            ImgixError::OutOfRange {
+                param,
+                value,
+                expected,
+            } => write!(
+                f,
+                "`{}` is out of range for `{}`, expected a value {}",
+                value, param, expected
+            ),
+            ImgixError::MissingParam { param, requires } => {
+                write!(f, "`{}` requires {} to be set", param, requires)
+            }
+            ImgixError::Conflict {
+                param,
+                first,
+                second,
+            } => write!(f, "`{}` cannot combine `{}` and `{}`", param, first, second),
+        }
+    }
+}
+
+impl error::Error for ImgixError {}
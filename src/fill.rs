@@ -0,0 +1,94 @@
+use crate::ImgixColor;
+
+/// The `fill` parameter controls how the empty area around the image is
+/// painted when `fit` is `fill` or `fillmax`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixColor, ImgixFill, ImgixFit, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .w(300)
+///     .h(300)
+///     .fit(ImgixFit::Fill)
+///     .fill(ImgixFill::Solid(ImgixColor::hex("ff0000").unwrap()))
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/cat.jpg?w=300&h=300&fit=fill&fill=solid&fill-color=ff0000"
+/// );
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/fill) for more info.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixFill {
+    /// Fills the empty area with a solid color.
+    Solid(ImgixColor),
+
+    /// Fills the empty area with a blurred version of the image.
+    Blur,
+
+    /// Fills the empty area with content generated to extend the image.
+    Generative {
+        /// A description of the content to generate.
+        prompt: Option<String>,
+
+        /// Seed for the generation, to get the same result across requests.
+        seed: Option<i32>,
+
+        /// Whether to fall back to a blur fill if generation fails.
+        fallback: Option<bool>,
+    },
+}
+
+impl ImgixFill {
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ImgixFill::Solid(color) => {
+                vec![("fill", "solid".into()), ("fill-color", color.to_string())]
+            }
+            ImgixFill::Blur => vec![("fill", "blur".into())],
+            ImgixFill::Generative {
+                prompt,
+                seed,
+                fallback,
+            } => {
+                let mut params = vec![("fill", "gen".into())];
+                if let Some(prompt) = prompt {
+                    params.push(("fill-gen-prompt", prompt.clone()));
+                }
+                if let Some(seed) = seed {
+                    params.push(("fill-gen-seed", seed.to_string()));
+                }
+                if let Some(fallback) = fallback {
+                    params.push(("fill-gen-fallback", fallback.to_string()));
+                }
+                params
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(
+            ImgixFill::Generative {
+                prompt: Some("beach".into()),
+                seed: None,
+                fallback: Some(true),
+            }
+            .params(),
+            vec![
+                ("fill", "gen".to_string()),
+                ("fill-gen-prompt", "beach".to_string()),
+                ("fill-gen-fallback", "true".to_string())
+            ]
+        );
+    }
+}
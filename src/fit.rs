@@ -1,4 +1,8 @@
-#[derive(Debug)]
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// The `fit` parameter controls how the output image is fit to its target
 /// dimensions after resizing, and how any background areas will be filled.
 ///
@@ -48,18 +52,37 @@ pub enum ImgixFit {
     Scale,
 }
 
-impl ToString for ImgixFit {
-    fn to_string(&self) -> String {
+impl fmt::Display for ImgixFit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ImgixFit::Clamp => "clamp".into(),
-            ImgixFit::Clip => "clip".into(),
-            ImgixFit::Crop => "crop".into(),
-            ImgixFit::FaceArea => "facearea".into(),
-            ImgixFit::Fill => "fill".into(),
-            ImgixFit::FillMax => "fillmax".into(),
-            ImgixFit::Max => "max".into(),
-            ImgixFit::Min => "min".into(),
-            ImgixFit::Scale => "scale".into(),
+            ImgixFit::Clamp => f.write_str("clamp"),
+            ImgixFit::Clip => f.write_str("clip"),
+            ImgixFit::Crop => f.write_str("crop"),
+            ImgixFit::FaceArea => f.write_str("facearea"),
+            ImgixFit::Fill => f.write_str("fill"),
+            ImgixFit::FillMax => f.write_str("fillmax"),
+            ImgixFit::Max => f.write_str("max"),
+            ImgixFit::Min => f.write_str("min"),
+            ImgixFit::Scale => f.write_str("scale"),
+        }
+    }
+}
+
+impl FromStr for ImgixFit {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clamp" => Ok(ImgixFit::Clamp),
+            "clip" => Ok(ImgixFit::Clip),
+            "crop" => Ok(ImgixFit::Crop),
+            "facearea" => Ok(ImgixFit::FaceArea),
+            "fill" => Ok(ImgixFit::Fill),
+            "fillmax" => Ok(ImgixFit::FillMax),
+            "max" => Ok(ImgixFit::Max),
+            "min" => Ok(ImgixFit::Min),
+            "scale" => Ok(ImgixFit::Scale),
+            _ => Err(ImgixError::invalid_value("fit", s)),
         }
     }
 }
@@ -0,0 +1,160 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
+/// The `fm` parameter changes the format of the output image.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixFormat, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .fm(ImgixFormat::Png8)
+///     .colorquant(64)
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?fm=png8&colorquant=64");
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/format/fm) for more info.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixFormat {
+    /// AV1 Image File Format.
+    Avif,
+
+    /// Graphics Interchange Format, limited to a 256 color palette.
+    Gif,
+
+    /// JPEG 2000.
+    Jp2,
+
+    /// Baseline JPEG.
+    Jpg,
+
+    /// A JSON document of the image's metadata instead of the image itself.
+    Json,
+
+    /// JPEG XR.
+    Jxr,
+
+    /// Progressive JPEG.
+    Pjpg,
+
+    /// MPEG-4 video, for animated source images.
+    Mp4,
+
+    /// Portable Network Graphics.
+    Png,
+
+    /// 8-bit PNG, limited to a 256 color palette.
+    Png8,
+
+    /// 32-bit PNG with an alpha channel.
+    Png32,
+
+    /// WebM video, for animated source images.
+    Webm,
+
+    /// WebP.
+    Webp,
+
+    /// A [BlurHash](https://blurha.sh) string representing a placeholder of the
+    /// image.
+    Blurhash,
+}
+
+impl fmt::Display for ImgixFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixFormat::Avif => f.write_str("avif"),
+            ImgixFormat::Gif => f.write_str("gif"),
+            ImgixFormat::Jp2 => f.write_str("jp2"),
+            ImgixFormat::Jpg => f.write_str("jpg"),
+            ImgixFormat::Json => f.write_str("json"),
+            ImgixFormat::Jxr => f.write_str("jxr"),
+            ImgixFormat::Pjpg => f.write_str("pjpg"),
+            ImgixFormat::Mp4 => f.write_str("mp4"),
+            ImgixFormat::Png => f.write_str("png"),
+            ImgixFormat::Png8 => f.write_str("png8"),
+            ImgixFormat::Png32 => f.write_str("png32"),
+            ImgixFormat::Webm => f.write_str("webm"),
+            ImgixFormat::Webp => f.write_str("webp"),
+            ImgixFormat::Blurhash => f.write_str("blurhash"),
+        }
+    }
+}
+
+impl FromStr for ImgixFormat {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "avif" => Ok(ImgixFormat::Avif),
+            "gif" => Ok(ImgixFormat::Gif),
+            "jp2" => Ok(ImgixFormat::Jp2),
+            "jpg" => Ok(ImgixFormat::Jpg),
+            "json" => Ok(ImgixFormat::Json),
+            "jxr" => Ok(ImgixFormat::Jxr),
+            "pjpg" => Ok(ImgixFormat::Pjpg),
+            "mp4" => Ok(ImgixFormat::Mp4),
+            "png" => Ok(ImgixFormat::Png),
+            "png8" => Ok(ImgixFormat::Png8),
+            "png32" => Ok(ImgixFormat::Png32),
+            "webm" => Ok(ImgixFormat::Webm),
+            "webp" => Ok(ImgixFormat::Webp),
+            "blurhash" => Ok(ImgixFormat::Blurhash),
+            _ => Err(ImgixError::invalid_value("fm", s)),
+        }
+    }
+}
+
+/// The `chromasub` parameter specifies the chroma subsampling of JPEG output.
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/format/chromasub) for
+/// more info.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixChromaSub {
+    /// 4:2:0 subsampling, the smallest output.
+    Yuv420,
+
+    /// 4:2:2 subsampling.
+    Yuv422,
+
+    /// 4:4:4 subsampling, which keeps all color information.
+    Yuv444,
+}
+
+impl fmt::Display for ImgixChromaSub {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixChromaSub::Yuv420 => f.write_str("420"),
+            ImgixChromaSub::Yuv422 => f.write_str("422"),
+            ImgixChromaSub::Yuv444 => f.write_str("444"),
+        }
+    }
+}
+
+impl FromStr for ImgixChromaSub {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "420" => Ok(ImgixChromaSub::Yuv420),
+            "422" => Ok(ImgixChromaSub::Yuv422),
+            "444" => Ok(ImgixChromaSub::Yuv444),
+            _ => Err(ImgixError::invalid_value("chromasub", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(ImgixFormat::Blurhash.to_string(), "blurhash");
+        assert_eq!("png32".parse(), Ok(ImgixFormat::Png32));
+        assert_eq!(ImgixChromaSub::Yuv444.to_string(), "444");
+    }
+}
@@ -13,39 +13,81 @@ In a nutshell, basic usage looks like this:
 use rs_imgix::ImgixUrl;
 
 fn main() {
-    let url = ImgixUrl::build("https://example.com")
+    let url = ImgixUrl::build("example.imgix.net", "photos/cat.jpg")
         .blur(40)
         .q(40)
         .w(300)
         .finish();
 
-    assert_eq!(url, "https://example.com/?blur=40&q=40&w=300");
+    assert_eq!(url, "https://example.imgix.net/photos/cat.jpg?blur=40&q=40&w=300");
 }
 ```
 
-Note that the `ImgixUrl` builder currently does not prevent construction of
-URLs that produce invalid behavior with Imgix's API. For example, it will not
-prevent the usage of the `ar` (Aspect Ratio) parameter if `fit=crop` is not
-specified.
+Note that `finish()` does not prevent construction of URLs that produce
+invalid behavior with Imgix's API. For example, it will not prevent the usage
+of the `ar` (Aspect Ratio) parameter if `fit=crop` is not specified. Use
+`try_finish()` instead to have the parameters validated first, and
+`warnings()` to find combinations that are valid but likely unintended.
+
+With the `serde` feature enabled, every parameter type and `ImgixUrlBuilder`
+implement `Serialize` and `Deserialize`, using Imgix's own string values.
+Types that set several parameters, such as `ImgixAdjust`, are serialized as a
+map of those parameters.
 
 For more information on Imgix's URL API, refer to their [documentation](https://docs.imgix.com/apis/url).
 */
 
-use qstring::QString;
-
+mod adjust;
+mod align;
 mod auto;
+mod blend;
 mod client_hints;
+mod color;
 mod color_space;
 mod crop;
+mod detail;
+mod encoding;
+mod error;
+mod fill;
 mod fit;
+mod format;
+mod mask;
+mod number;
+mod orient;
+mod radius;
 mod rect;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod srcset;
+mod text;
+mod trim;
+mod validate;
+mod warning;
+mod watermark;
 
+pub use crate::adjust::ImgixAdjust;
+pub use crate::align::ImgixAlign;
 pub use crate::auto::ImgixAuto;
+pub use crate::blend::{ImgixBlend, ImgixBlendMode};
 pub use crate::client_hints::ImgixClientHints;
+pub use crate::color::ImgixColor;
 pub use crate::color_space::ImgixColorSpace;
 pub use crate::crop::ImgixCrop;
+pub use crate::detail::ImgixDetail;
+pub use crate::error::ImgixError;
+pub use crate::fill::ImgixFill;
 pub use crate::fit::ImgixFit;
+pub use crate::format::{ImgixChromaSub, ImgixFormat};
+pub use crate::mask::ImgixMask;
+pub use crate::number::ImgixNumber;
+pub use crate::orient::{ImgixFlip, ImgixOrient};
+pub use crate::radius::ImgixRadius;
 pub use crate::rect::{Direction, ImgixRect, X, Y};
+pub use crate::srcset::ImgixSrcSet;
+pub use crate::text::{ImgixFontFamily, ImgixFontStyle, ImgixText, ImgixTextClip};
+pub use crate::trim::ImgixTrim;
+pub use crate::warning::ImgixWarning;
+pub use crate::watermark::ImgixWatermark;
 
 /// A builder for Imgix URLs. Begin constructing a new URL by calling `build()`.
 ///
@@ -53,37 +95,444 @@ pub use crate::rect::{Direction, ImgixRect, X, Y};
 /// ```
 /// use rs_imgix::ImgixUrl;
 ///
-/// let url = ImgixUrl::build("https://foo.com").blur(20).finish();
-/// assert_eq!(url, "https://foo.com/?blur=20");
+/// let url = ImgixUrl::build("foo.imgix.net", "/cat.jpg").blur(20).finish();
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?blur=20");
 /// ```
 #[derive(Debug)]
 pub struct ImgixUrl;
 
 impl ImgixUrl {
-    /// Starts building a Imgix URL. Returns an `ImgixUrlBuilder` to add
-    /// additional query parameters to the URL.
-    pub fn build(url: &str) -> ImgixUrlBuilder<'_> {
+    /// Starts building a Imgix URL for the image at `path` on `domain`.
+    /// Returns an `ImgixUrlBuilder` to add additional query parameters to the
+    /// URL.
+    ///
+    /// The domain may include a scheme, otherwise `https` is used. Each segment
+    /// of the path is percent-encoded. A fully qualified URL may be given as
+    /// the path for Web Proxy sources, in which case it is encoded as a whole.
+    pub fn build(domain: &str, path: &str) -> ImgixUrlBuilder {
+        let domain = domain.trim_end_matches('/');
+        let domain = if domain.contains("://") {
+            domain.into()
+        } else {
+            format!("https://{}", domain)
+        };
+
         ImgixUrlBuilder {
             params: Vec::new(),
-            url: url.into(),
+            domain,
+            path: path.into(),
+            token: None,
+            raw_path: None,
+            raw_params: Vec::new(),
+        }
+    }
+
+    /// Parses an existing Imgix URL back into an `ImgixUrlBuilder`, so that
+    /// its parameters can be adjusted.
+    ///
+    /// Values of known parameters are checked against their types. The path
+    /// and parameters keep the encoding they have in `url` unless they are
+    /// changed. The `s` parameter is dropped, as the signature is recomputed
+    /// by `finish()` when a secure token is provided.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_imgix::ImgixUrl;
+    ///
+    /// let url = ImgixUrl::parse("https://foo.imgix.net/cat.jpg?w=300&fit=crop")
+    ///     .unwrap()
+    ///     .h(200)
+    ///     .finish();
+    ///
+    /// assert_eq!(url, "https://foo.imgix.net/cat.jpg?w=300&fit=crop&h=200");
+    /// ```
+    pub fn parse(url: &str) -> Result<ImgixUrlBuilder, ImgixError> {
+        let invalid = || ImgixError::InvalidUrl(url.into());
+        let url_no_fragment = url.split('#').next().unwrap_or_default();
+        let (scheme, rest) = url_no_fragment.split_once("://").ok_or_else(invalid)?;
+        let (rest, qs) = rest.split_once('?').unwrap_or((rest, ""));
+        let (host, path) = rest.find('/').map_or((rest, ""), |i| rest.split_at(i));
+
+        if scheme.is_empty() || host.is_empty() {
+            return Err(invalid());
+        }
+
+        let domain = format!("{}://{}", scheme, host);
+        let mut builder = ImgixUrl::build(&domain, &encoding::decode(path));
+        builder.raw_path = Some(path.into());
+
+        for pair in qs.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, val) = pair.split_once('=').unwrap_or((pair, ""));
+            let (key, val) = (encoding::decode(key), encoding::decode(val));
+
+            if key != "s" {
+                builder.set_typed(&key, &val)?;
+                builder.raw_params.push((key, pair.into()));
+            }
         }
+
+        Ok(builder)
     }
 }
 
 /// Builder for specifying URL parameters to add to the constructed URL.
-#[derive(Debug)]
-pub struct ImgixUrlBuilder<'a> {
-    params: Vec<(&'a str, String)>,
-    url: String,
+///
+/// Setting a parameter that is already set replaces its value.
+///
+/// A builder can be cloned to act as a preset, and applied to other images
+/// with `path()` or combined with other parameters with `merge()`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixFit, ImgixUrl};
+///
+/// let mut card = ImgixUrl::build("foo.imgix.net", "");
+/// card.w(300).h(200).fit(ImgixFit::Crop);
+///
+/// let mut overrides = ImgixUrl::build("foo.imgix.net", "");
+/// overrides.w(600).q(80);
+///
+/// let url = card.clone().path("cat.jpg").merge(&overrides).finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?w=600&h=200&fit=crop&q=80");
+/// assert_eq!(
+///     card.path("dog.jpg").finish(),
+///     "https://foo.imgix.net/dog.jpg?w=300&h=200&fit=crop"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImgixUrlBuilder {
+    params: Vec<(String, String)>,
+    domain: String,
+    path: String,
+    token: Option<String>,
+
+    /// The path as it was encoded in a parsed URL.
+    raw_path: Option<String>,
+
+    /// The `key=val` pairs as they were encoded in a parsed URL, by key.
+    raw_params: Vec<(String, String)>,
 }
 
-impl<'a> ImgixUrlBuilder<'a> {
+impl ImgixUrlBuilder {
     /// Completes the construction of the URL and returns the final URL with
     /// query string parametrs.
+    ///
+    /// If a secure token was provided with `secure_token()`, the URL is signed
+    /// and the `s` parameter is appended as the last query parameter.
     pub fn finish(&self) -> String {
-        let qs = QString::new(self.params.to_owned());
+        self.url_with(&self.params)
+    }
+
+    /// Completes the construction of the URL like `finish()`, but first checks
+    /// the parameters against the rules of Imgix's URL API. Returns the first
+    /// violated rule, such as an out of range value, a parameter missing one it
+    /// depends on, or conflicting values.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_imgix::{ImgixError, ImgixUrl};
+    ///
+    /// let err = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+    ///     .ar(16, 9)
+    ///     .try_finish()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err,
+    ///     ImgixError::MissingParam {
+    ///         param: "ar".into(),
+    ///         requires: "fit=crop".into(),
+    ///     }
+    /// );
+    /// ```
+    pub fn try_finish(&self) -> Result<String, ImgixError> {
+        validate::validate(&self.params)?;
+
+        Ok(self.finish())
+    }
+
+    /// Returns the parameter combinations that Imgix accepts, but that are
+    /// likely to give unexpected results. Unlike `try_finish()`, these do not
+    /// prevent the URL from being built.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_imgix::{ImgixUrl, ImgixWarning};
+    ///
+    /// let mut builder = ImgixUrl::build("foo.imgix.net", "cat.jpg");
+    /// builder.rot(45.0);
+    ///
+    /// assert_eq!(
+    ///     builder.warnings(),
+    ///     vec![ImgixWarning::ExposedCorners { rot: "45".into() }]
+    /// );
+    /// ```
+    pub fn warnings(&self) -> Vec<ImgixWarning> {
+        validate::warnings(&self.params)
+    }
+
+    /// Returns a `srcset` attribute value for the image.
+    ///
+    /// Fixed-size images, where `w` is set (or `h` along with `ar`), get a
+    /// candidate for each pixel density from `1x` to `5x`. Other images get
+    /// a candidate for each width of a fluid-width ladder. Each candidate URL
+    /// keeps every other parameter of the builder.
+    ///
+    /// See `ImgixSrcSet` for the available options.
+    pub fn srcset(&self, opts: ImgixSrcSet) -> String {
+        let fixed = self.has("w") || (self.has("h") && self.has("ar"));
+
+        if fixed {
+            (1..=5)
+                .map(|dpr| {
+                    let mut params = self.params.clone();
+                    params.retain(|(key, _)| key != "dpr");
+                    params.push(("dpr".into(), dpr.to_string()));
+
+                    if let (false, Some(q)) = (self.has("q"), opts.quality(dpr)) {
+                        params.push(("q".into(), q.to_string()));
+                    }
+
+                    format!("{} {}x", self.url_with(&params), dpr)
+                })
+                .collect::<Vec<_>>()
+                .join(",\n")
+        } else {
+            opts.widths()
+                .into_iter()
+                .map(|w| {
+                    let mut params = self.params.clone();
+                    params.retain(|(key, _)| key != "w");
+                    params.push(("w".into(), w.to_string()));
+
+                    format!("{} {}w", self.url_with(&params), w)
+                })
+                .collect::<Vec<_>>()
+                .join(",\n")
+        }
+    }
+
+    /// Returns the current value of the parameter `key`, if it is set.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_imgix::ImgixUrl;
+    ///
+    /// let mut builder = ImgixUrl::build("foo.imgix.net", "cat.jpg");
+    /// builder.w(300).q(40).w(600);
+    ///
+    /// assert_eq!(builder.get("w"), Some("600"));
+    /// assert_eq!(builder.remove("q").get("q"), None);
+    /// assert_eq!(builder.finish(), "https://foo.imgix.net/cat.jpg?w=600");
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Removes the parameter `key`, if it is set.
+    pub fn remove(&mut self, key: &str) -> &mut Self {
+        self.params.retain(|(k, _)| k != key);
+        self
+    }
+
+    /// Returns whether the parameter `key` is set.
+    fn has(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Sets the parameter `key` to `val`. A parameter that is already set keeps
+    /// its position and only has its value replaced.
+    fn set(&mut self, key: impl Into<String>, val: String) -> &mut Self {
+        set_param(&mut self.params, key.into(), val);
+        self
+    }
+
+    /// Constructs the URL for the builder's domain and path with the given
+    /// parameters, signing it if a secure token was provided.
+    fn url_with(&self, params: &[(String, String)]) -> String {
+        let path = match &self.raw_path {
+            Some(raw) if encoding::decode(raw) == self.path => raw.clone(),
+            _ => encoding::path(&self.path),
+        };
+        let mut qs = params
+            .iter()
+            .map(|(key, val)| {
+                self.raw_pair(key, val)
+                    .unwrap_or_else(|| encoding::pair(key, val))
+            })
+            .collect::<Vec<_>>()
+            .join("&");
 
-        format!("{}/?{}", self.url, qs)
+        if let Some(token) = &self.token {
+            let s = signature(token, &path, &qs);
+
+            if !qs.is_empty() {
+                qs.push('&');
+            }
+            qs.push_str("s=");
+            qs.push_str(&s);
+        }
+
+        if qs.is_empty() {
+            format!("{}{}", self.domain, path)
+        } else {
+            format!("{}{}?{}", self.domain, path, qs)
+        }
+    }
+
+    /// Returns the pair for `key` as it was encoded in a parsed URL, if its
+    /// value is still `val`.
+    fn raw_pair(&self, key: &str, val: &str) -> Option<String> {
+        self.raw_params
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, pair)| pair)
+            .find(|pair| {
+                let raw_val = pair.split_once('=').map_or("", |(_, v)| v);
+                encoding::decode(raw_val) == val
+            })
+            .cloned()
+    }
+
+    /// Signs the constructed URL with the secure URL token of an Imgix source.
+    /// Sources that are marked as secure reject any URL without a valid `s`
+    /// parameter.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_imgix::ImgixUrl;
+    ///
+    /// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+    ///     .w(300)
+    ///     .secure_token("FOO123bar")
+    ///     .finish();
+    ///
+    /// assert_eq!(url, "https://foo.imgix.net/cat.jpg?w=300&s=474e0359a06a37cd5241a26e64dba5ae");
+    /// ```
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/setup/securing-images) for more
+    /// info.
+    pub fn secure_token(&mut self, token: &str) -> &mut Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Replaces the path of the image, keeping every parameter. Useful to apply
+    /// a preset to many images.
+    pub fn path(&mut self, path: &str) -> &mut Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Sets every parameter of `other` on this builder, replacing the values
+    /// of parameters that are set on both. The domain, path and secure token
+    /// of `other` are ignored.
+    pub fn merge(&mut self, other: &ImgixUrlBuilder) -> &mut Self {
+        for (key, val) in &other.params {
+            self.set(key.clone(), val.clone());
+        }
+
+        self
+    }
+
+    /// Sets a parameter that the builder has no dedicated method for. The
+    /// value is percent-encoded as needed.
+    pub fn param(&mut self, key: &str, val: &str) -> &mut Self {
+        self.set(key, val.into())
+    }
+
+    /// Sets the parameter `key` from its string value. Values of known
+    /// parameters are checked against their type so that invalid values are
+    /// rejected, and are then kept as written so that they round-trip
+    /// unchanged.
+    fn set_typed(&mut self, key: &str, val: &str) -> Result<&mut Self, ImgixError> {
+        let invalid = || ImgixError::invalid_value(key, val);
+        let is_number = |n: &str| n.parse::<f64>().is_ok_and(f64::is_finite);
+        let number = || {
+            if is_number(val) {
+                Ok(())
+            } else {
+                Err(invalid())
+            }
+        };
+        let float = || val.parse::<f64>().map_err(|_| invalid());
+        let boolean = || match val {
+            "1" | "true" => Ok(true),
+            "0" | "false" => Ok(false),
+            _ => Err(invalid()),
+        };
+
+        match key {
+            "q" | "w" | "h" | "min-w" | "min-h" | "max-w" | "max-h" | "dpr" | "blur"
+            | "duotone-alpha" | "htn" | "px" | "sepia" | "faceindex" | "facepad" | "colorquant"
+            | "dpi" | "pad" | "pad-top" | "pad-right" | "pad-bottom" | "pad-left"
+            | "border-top" | "border-right" | "border-bottom" | "border-left" => number()?,
+            "trim" if val != "auto" && val != "color" => return Err(invalid()),
+            "fill" if val != "solid" && val != "blur" && val != "gen" => return Err(invalid()),
+            "fill-gen-seed" if val.parse::<i32>().is_err() => return Err(invalid()),
+            _ if validate::COLORS.contains(&key) => validate::check_color(key, val)?,
+            "ar" => {
+                let (w, h) = val.split_once(':').ok_or_else(invalid)?;
+                if !is_number(w) || !is_number(h) {
+                    return Err(invalid());
+                }
+            }
+            "auto" => {
+                val.parse::<ImgixAuto>()?;
+            }
+            "rect" => {
+                val.parse::<ImgixRect>()?;
+            }
+            "fit" => {
+                val.parse::<ImgixFit>()?;
+            }
+            "crop" => {
+                val.parse::<ImgixCrop>()?;
+            }
+            "fp-x" | "fp-y" | "fp-z" | "rot" => {
+                float()?;
+            }
+            "fill-gen-fallback" if val != "true" && val != "false" => return Err(invalid()),
+            "fp-debug" | "lossless" => {
+                boolean()?;
+            }
+            "cs" => {
+                val.parse::<ImgixColorSpace>()?;
+            }
+            "ch" => {
+                val.parse::<ImgixClientHints>()?;
+            }
+            "fm" => {
+                val.parse::<ImgixFormat>()?;
+            }
+            "chromasub" => {
+                val.parse::<ImgixChromaSub>()?;
+            }
+            "border" => {
+                let (width, color) = val.split_once(',').ok_or_else(invalid)?;
+                if !is_number(width) {
+                    return Err(invalid());
+                }
+                color.parse::<ImgixColor>()?;
+            }
+            "mask" => mask::check(val)?,
+            "border-radius" | "border-radius-inner" | "corner-radius" => {
+                val.parse::<ImgixRadius>()?;
+            }
+            "orient" => {
+                val.parse::<ImgixOrient>()?;
+            }
+            "flip" => {
+                val.parse::<ImgixFlip>()?;
+            }
+            _ => {}
+        }
+
+        Ok(self.param(key, val))
     }
 
     /// Controls the output quality of lossy file formats.
@@ -91,8 +540,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/format/q) for more info.
     pub fn q(&mut self, val: i32) -> &mut Self {
-        self.params.push(("q", val.to_string()));
-        self
+        self.set("q", val.to_string())
     }
 
     /// The width of the output image, interpreted as pixels. The resulting
@@ -100,8 +548,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/size/w) for more info.
     pub fn w(&mut self, val: i32) -> &mut Self {
-        self.params.push(("w", val.to_string()));
-        self
+        self.set("w", val.to_string())
     }
 
     /// The height of the output image, interpreted as pixels. The resulting
@@ -109,8 +556,43 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/size/h) for more info.
     pub fn h(&mut self, val: i32) -> &mut Self {
-        self.params.push(("h", val.to_string()));
-        self
+        self.set("h", val.to_string())
+    }
+
+    /// The minimum width of the output image, in pixels. Only used when `fit`
+    /// is `crop`, `min` or `max`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/size/min-w) for more
+    /// info.
+    pub fn min_w(&mut self, val: i32) -> &mut Self {
+        self.set("min-w", val.to_string())
+    }
+
+    /// The minimum height of the output image, in pixels. Only used when `fit`
+    /// is `crop`, `min` or `max`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/size/min-h) for more
+    /// info.
+    pub fn min_h(&mut self, val: i32) -> &mut Self {
+        self.set("min-h", val.to_string())
+    }
+
+    /// The maximum width of the output image, in pixels. Only used when `fit`
+    /// is `crop`, `min` or `max`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/size/max-w) for more
+    /// info.
+    pub fn max_w(&mut self, val: i32) -> &mut Self {
+        self.set("max-w", val.to_string())
+    }
+
+    /// The maximum height of the output image, in pixels. Only used when `fit`
+    /// is `crop`, `min` or `max`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/size/max-h) for more
+    /// info.
+    pub fn max_h(&mut self, val: i32) -> &mut Self {
+        self.set("max-h", val.to_string())
     }
 
     /// Controls the output density of your image, so you can serve images at
@@ -119,8 +601,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     /// See [Imgix docs](https://docs.imgix.com/apis/url/pixel-density/dpr) for
     /// more info.
     pub fn dpr(&mut self, val: i32) -> &mut Self {
-        self.params.push(("dpr", val.to_string()));
-        self
+        self.set("dpr", val.to_string())
     }
 
     /// The bg parameter allows you to fill in any transparent areas in your
@@ -128,9 +609,8 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/fill/bg) for
     /// more info.
-    pub fn bg(&mut self, val: &str) -> &mut Self {
-        self.params.push(("bg", val.into()));
-        self
+    pub fn bg(&mut self, color: ImgixColor) -> &mut Self {
+        self.set("bg", color.to_string())
     }
 
     /// Applies a Gaussian style blur to your image, smoothing out image noise.
@@ -139,8 +619,59 @@ impl<'a> ImgixUrlBuilder<'a> {
     /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/blur) for
     /// more info.
     pub fn blur(&mut self, val: i32) -> &mut Self {
-        self.params.push(("blur", val.to_string()));
-        self
+        self.set("blur", val.to_string())
+    }
+
+    /// Applies a duotone effect, mapping the shadows of the image to `shadow`
+    /// and its highlights to `highlight`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/duotone) for
+    /// more info.
+    pub fn duotone(&mut self, shadow: ImgixColor, highlight: ImgixColor) -> &mut Self {
+        self.set("duotone", format!("{},{}", shadow, highlight))
+    }
+
+    /// Controls the opacity of the `duotone` effect. Valid values are in the
+    /// range of 0 - 100.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/duotone-alpha)
+    /// for more info.
+    pub fn duotone_alpha(&mut self, val: i32) -> &mut Self {
+        self.set("duotone-alpha", val.to_string())
+    }
+
+    /// Applies a halftone effect, where `val` controls the size of the dots.
+    /// Valid values are in the range of 0 - 100.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/htn) for more
+    /// info.
+    pub fn htn(&mut self, val: i32) -> &mut Self {
+        self.set("htn", val.to_string())
+    }
+
+    /// Applies a monochrome effect, tinting the image with `color`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/monochrome) for
+    /// more info.
+    pub fn monochrome(&mut self, color: ImgixColor) -> &mut Self {
+        self.set("monochrome", color.to_string())
+    }
+
+    /// Applies a pixelation effect, where `val` controls the size of the
+    /// pixels. Valid values are in the range of 0 - 100.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/px) for more
+    /// info.
+    pub fn px(&mut self, val: i32) -> &mut Self {
+        self.set("px", val.to_string())
+    }
+
+    /// Applies a sepia effect. Valid values are in the range of 0 - 100.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/stylize/sepia) for more
+    /// info.
+    pub fn sepia(&mut self, val: i32) -> &mut Self {
+        self.set("sepia", val.to_string())
     }
 
     /// The faceindex parameter selects a face on which to center an image when
@@ -152,8 +683,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     /// See [Imgix docs](https://docs.imgix.com/apis/url/face-detection/faceindex) for more
     /// info.
     pub fn faceindex(&mut self, val: i32) -> &mut Self {
-        self.params.push(("faceindex", val.to_string()));
-        self
+        self.set("faceindex", val.to_string())
     }
 
     /// The facepad parameter defines how much padding to allow for each face
@@ -161,8 +691,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/face-detection/facepad) for more info.
     pub fn facepad(&mut self, val: i32) -> &mut Self {
-        self.params.push(("facepad", val.to_string()));
-        self
+        self.set("facepad", val.to_string())
     }
 
     /// Resizes and crops the original image to match a specified aspect ratio.
@@ -170,8 +699,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/size/ar) for more info.
     pub fn ar(&mut self, w: i32, h: i32) -> &mut Self {
-        self.params.push(("ar", format!("{}:{}", w, h)));
-        self
+        self.set("ar", format!("{}:{}", w, h))
     }
 
     /// The `auto` parameter helps you automate a baseline level of optimization
@@ -179,9 +707,8 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/auto/auto) for more
     /// info.
-    pub fn auto(&mut self, auto: auto::ImgixAuto<'_>) -> &mut Self {
-        self.params.push(("auto", auto.to_string()));
-        self
+    pub fn auto(&mut self, auto: auto::ImgixAuto) -> &mut Self {
+        self.set("auto", auto.to_string())
     }
 
     /// The `rect` parameter selects a sub-region of the source image to use for
@@ -190,8 +717,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     /// See [Imgix docs](https://docs.imgix.com/apis/url/size/rect) for more
     /// info.
     pub fn rect(&mut self, rect: rect::ImgixRect) -> &mut Self {
-        self.params.push(("rect", rect.to_string()));
-        self
+        self.set("rect", rect.to_string())
     }
 
     /// The `fit` parameter controls how the output image is fit to its target
@@ -200,8 +726,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     /// See [Imgix docs](https://docs.imgix.com/apis/url/size/fit) for more
     /// info.
     pub fn fit(&mut self, fit: fit::ImgixFit) -> &mut Self {
-        self.params.push(("fit", fit.to_string()));
-        self
+        self.set("fit", fit.to_string())
     }
 
     /// Crop mode controls how the image is aligned when `fit=crop` is set. The
@@ -210,9 +735,73 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/size/crop) for more
     /// info.
-    pub fn crop(&mut self, crop: crop::ImgixCrop<'_>) -> &mut Self {
-        self.params.push(("crop", crop.to_string()));
-        self
+    pub fn crop(&mut self, crop: crop::ImgixCrop) -> &mut Self {
+        self.set("crop", crop.to_string())
+    }
+
+    /// Sets the horizontal position of the focal point, as a fraction of the
+    /// image width from 0 to 1. Only valid when `fit=crop` and
+    /// `crop=focalpoint` are set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/focalpoint-crop/fp-x)
+    /// for more info.
+    pub fn fp_x(&mut self, val: f64) -> &mut Self {
+        self.set("fp-x", round(val).to_string())
+    }
+
+    /// Sets the vertical position of the focal point, as a fraction of the
+    /// image height from 0 to 1. Only valid when `fit=crop` and
+    /// `crop=focalpoint` are set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/focalpoint-crop/fp-y)
+    /// for more info.
+    pub fn fp_y(&mut self, val: f64) -> &mut Self {
+        self.set("fp-y", round(val).to_string())
+    }
+
+    /// Zooms in on the focal point, from 1 (no zoom) to 100.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/focalpoint-crop/fp-z)
+    /// for more info.
+    pub fn fp_z(&mut self, val: f64) -> &mut Self {
+        self.set("fp-z", round(val).to_string())
+    }
+
+    /// Overlays crosshairs on the focal point, to help position it.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/focalpoint-crop/fp-debug)
+    /// for more info.
+    pub fn fp_debug(&mut self, val: bool) -> &mut Self {
+        self.set("fp-debug", if val { "1" } else { "0" }.into())
+    }
+
+    /// Sets `fp-x` and `fp-y` from a point picked on the original image, given
+    /// in pixels along with the dimensions of the original image. The
+    /// fractions are rounded to 4 decimal places.
+    ///
+    /// A zero or negative `width` or `height` gives a fraction outside of 0 to
+    /// 1, which `try_finish()` rejects.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_imgix::{ImgixCrop, ImgixFit, ImgixUrl};
+    ///
+    /// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+    ///     .w(300)
+    ///     .fit(ImgixFit::Crop)
+    ///     .crop(ImgixCrop::build().focalpoint().finish())
+    ///     .fp_pixel(1000, 250, 3000, 2000)
+    ///     .finish();
+    ///
+    /// assert_eq!(
+    ///     url,
+    ///     "https://foo.imgix.net/cat.jpg?w=300&fit=crop&crop=focalpoint&fp-x=0.3333&fp-y=0.125"
+    /// );
+    /// ```
+    pub fn fp_pixel(&mut self, x: i32, y: i32, width: i32, height: i32) -> &mut Self {
+        let fraction = |pos: i32, size: i32| f64::from(pos) / f64::from(size);
+
+        self.fp_x(fraction(x, width)).fp_y(fraction(y, height))
     }
 
     /// The `cs` parameter specifies the color space of the output image.
@@ -220,8 +809,7 @@ impl<'a> ImgixUrlBuilder<'a> {
     /// See [Imgix docs](https://docs.imgix.com/apis/url/format/cs) for more
     /// info.
     pub fn cs(&mut self, cs: color_space::ImgixColorSpace) -> &mut Self {
-        self.params.push(("cs", cs.to_string()));
-        self
+        self.set("cs", cs.to_string())
     }
 
     /// The ch parameter opts in specific images to use [Client
@@ -230,19 +818,349 @@ impl<'a> ImgixUrlBuilder<'a> {
     ///
     /// See [Imgix docs](https://docs.imgix.com/apis/url/format/ch) for more
     /// info.
-    pub fn ch(&mut self, ch: client_hints::ImgixClientHints<'_>) -> &mut Self {
-        self.params.push(("ch", ch.to_string()));
+    pub fn ch(&mut self, ch: client_hints::ImgixClientHints) -> &mut Self {
+        self.set("ch", ch.to_string())
+    }
+
+    /// The `fm` parameter changes the format of the output image.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/format/fm) for more
+    /// info.
+    pub fn fm(&mut self, fm: ImgixFormat) -> &mut Self {
+        self.set("fm", fm.to_string())
+    }
+
+    /// Enables or disables lossless compression. Only valid when `fm` is
+    /// `webp`, `jxr` or `avif`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/format/lossless) for
+    /// more info.
+    pub fn lossless(&mut self, val: bool) -> &mut Self {
+        self.set("lossless", if val { "1" } else { "0" }.into())
+    }
+
+    /// Specifies the chroma subsampling of the output image. Only valid when
+    /// `fm` is `jpg` or `pjpg`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/format/chromasub) for
+    /// more info.
+    pub fn chromasub(&mut self, chromasub: ImgixChromaSub) -> &mut Self {
+        self.set("chromasub", chromasub.to_string())
+    }
+
+    /// Limits the number of unique colors in the output image. Only valid when
+    /// `fm` is a palette format, `gif` or `png8`. Valid values are in the range
+    /// of 2 - 256.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/format/colorquant) for
+    /// more info.
+    pub fn colorquant(&mut self, val: i32) -> &mut Self {
+        self.set("colorquant", val.to_string())
+    }
+
+    /// Sets the DPI value in the metadata of the output image. Only valid when
+    /// `fm` is a JPEG or PNG format.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/format/dpi) for more
+    /// info.
+    pub fn dpi(&mut self, val: i32) -> &mut Self {
+        self.set("dpi", val.to_string())
+    }
+
+    /// Sets a group of adjustment parameters, such as `bri`, `con` and `sat`,
+    /// replacing any values already set for them.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/adjustment) for more
+    /// info.
+    pub fn adjust(&mut self, adjust: ImgixAdjust) -> &mut Self {
+        for (key, val) in adjust.params() {
+            self.set(*key, val.clone());
+        }
+
+        self
+    }
+
+    /// Sets a group of noise reduction and sharpening parameters, such as `nr`
+    /// and `usm`, replacing any values already set for them.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/noise-reduction) for
+    /// more info.
+    pub fn detail(&mut self, detail: ImgixDetail) -> &mut Self {
+        for (key, val) in detail.params() {
+            self.set(*key, val.clone());
+        }
+
+        self
+    }
+
+    /// Draws text over the image. Sets `txt` (or `txt64`) and the `txt-*`
+    /// parameters as a group, replacing any text already set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/text) for more info.
+    pub fn txt(&mut self, text: ImgixText) -> &mut Self {
+        self.params
+            .retain(|(k, _)| k != "txt" && k != "txt64" && !k.starts_with("txt-"));
+
+        for (key, val) in text.params() {
+            self.set(*key, val.clone());
+        }
+
+        self
+    }
+
+    /// Draws a watermark over the image. Sets `mark64` and the `mark-*`
+    /// parameters as a group, replacing any watermark already set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/watermark) for more
+    /// info.
+    pub fn mark(&mut self, mark: ImgixWatermark) -> &mut Self {
+        self.params
+            .retain(|(k, _)| k != "mark" && k != "mark64" && !k.starts_with("mark-"));
+
+        for (key, val) in mark.params() {
+            self.set(*key, val.clone());
+        }
+
+        self
+    }
+
+    /// Blends a color or image over the image. Sets `blend` (or `blend64`) and
+    /// the `blend-*` parameters as a group, replacing any blend already set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/blending) for more
+    /// info.
+    pub fn blend(&mut self, blend: ImgixBlend) -> &mut Self {
+        self.params
+            .retain(|(k, _)| k != "blend" && k != "blend64" && !k.starts_with("blend-"));
+
+        for (key, val) in blend.params() {
+            self.set(*key, val.clone());
+        }
+
+        self
+    }
+
+    /// Pads every side of the image with `val` pixels, filled with `bg`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/pad)
+    /// for more info.
+    pub fn pad(&mut self, val: i32) -> &mut Self {
+        self.set("pad", val.to_string())
+    }
+
+    /// Pads the top of the image with `val` pixels, overriding `pad`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/pad-top)
+    /// for more info.
+    pub fn pad_top(&mut self, val: i32) -> &mut Self {
+        self.set("pad-top", val.to_string())
+    }
+
+    /// Pads the right of the image with `val` pixels, overriding `pad`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/pad-right)
+    /// for more info.
+    pub fn pad_right(&mut self, val: i32) -> &mut Self {
+        self.set("pad-right", val.to_string())
+    }
+
+    /// Pads the bottom of the image with `val` pixels, overriding `pad`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/pad-bottom)
+    /// for more info.
+    pub fn pad_bottom(&mut self, val: i32) -> &mut Self {
+        self.set("pad-bottom", val.to_string())
+    }
+
+    /// Pads the left of the image with `val` pixels, overriding `pad`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/pad-left)
+    /// for more info.
+    pub fn pad_left(&mut self, val: i32) -> &mut Self {
+        self.set("pad-left", val.to_string())
+    }
+
+    /// Draws a border of `width` pixels and `color` around the image.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border)
+    /// for more info.
+    pub fn border(&mut self, width: i32, color: ImgixColor) -> &mut Self {
+        self.set("border", format!("{},{}", width, color))
+    }
+
+    /// Sets the width of the top border in pixels, overriding the width
+    /// given to `border`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border-top)
+    /// for more info.
+    pub fn border_top(&mut self, val: i32) -> &mut Self {
+        self.set("border-top", val.to_string())
+    }
+
+    /// Sets the width of the right border in pixels, overriding the width
+    /// given to `border`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border-right)
+    /// for more info.
+    pub fn border_right(&mut self, val: i32) -> &mut Self {
+        self.set("border-right", val.to_string())
+    }
+
+    /// Sets the width of the bottom border in pixels, overriding the width
+    /// given to `border`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border-bottom)
+    /// for more info.
+    pub fn border_bottom(&mut self, val: i32) -> &mut Self {
+        self.set("border-bottom", val.to_string())
+    }
+
+    /// Sets the width of the left border in pixels, overriding the width
+    /// given to `border`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border-left)
+    /// for more info.
+    pub fn border_left(&mut self, val: i32) -> &mut Self {
+        self.set("border-left", val.to_string())
+    }
+
+    /// Rounds the outer corners of the `border`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border-radius)
+    /// for more info.
+    pub fn border_radius(&mut self, radius: ImgixRadius) -> &mut Self {
+        self.set("border-radius", radius.to_string())
+    }
+
+    /// Rounds the inner corners of the `border`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/border-and-padding/border-radius-inner)
+    /// for more info.
+    pub fn border_radius_inner(&mut self, radius: ImgixRadius) -> &mut Self {
+        self.set("border-radius-inner", radius.to_string())
+    }
+
+    /// Masks the image with an ellipse, rounded corners, or another image. Sets
+    /// `mask` along with `corner-radius`, replacing any mask already set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/mask/mask) for more
+    /// info.
+    pub fn mask(&mut self, mask: ImgixMask) -> &mut Self {
+        self.remove("corner-radius");
+
+        for (key, val) in mask.params() {
+            self.set(key, val);
+        }
+
+        self
+    }
+
+    /// Fills the area of the image outside of the `mask` with `color`, instead
+    /// of leaving it transparent.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/mask/mask-bg) for more
+    /// info.
+    pub fn mask_bg(&mut self, color: ImgixColor) -> &mut Self {
+        self.set("mask-bg", color.to_string())
+    }
+
+    /// Changes the cardinal orientation of the image, overriding its EXIF
+    /// orientation.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/rotation/orient) for
+    /// more info.
+    pub fn orient(&mut self, orient: ImgixOrient) -> &mut Self {
+        self.set("orient", orient.to_string())
+    }
+
+    /// Flips the image horizontally, vertically, or both.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/rotation/flip) for
+    /// more info.
+    pub fn flip(&mut self, flip: ImgixFlip) -> &mut Self {
+        self.set("flip", flip.to_string())
+    }
+
+    /// Rotates the image counter-clockwise by `val` degrees, from 0 to 359,
+    /// rounded to 4 decimal places.
+    /// Rotations that are not a multiple of 90 expose the corners of the
+    /// canvas, which are filled with `bg`.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/rotation/rot) for
+    /// more info.
+    pub fn rot(&mut self, val: f64) -> &mut Self {
+        self.set("rot", round(val).to_string())
+    }
+
+    /// Trims the uniform border around the image, replacing any trim
+    /// parameters already set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/trim/trim) for more
+    /// info.
+    pub fn trim(&mut self, trim: ImgixTrim) -> &mut Self {
+        for key in &["trim-md", "trim-sd", "trim-color", "trim-tol"] {
+            self.remove(key);
+        }
+
+        for (key, val) in trim.params() {
+            self.set(key, val);
+        }
+
+        self
+    }
+
+    /// Controls how the empty area around the image is painted when `fit` is
+    /// `fill` or `fillmax`. Sets `fill` along with `fill-color` or the
+    /// `fill-gen-*` parameters, replacing any fill already set.
+    ///
+    /// See [Imgix docs](https://docs.imgix.com/apis/url/fill/fill) for more
+    /// info.
+    pub fn fill(&mut self, fill: ImgixFill) -> &mut Self {
+        self.params
+            .retain(|(k, _)| k != "fill-color" && !k.starts_with("fill-gen-"));
+
+        for (key, val) in fill.params() {
+            self.set(key, val);
+        }
+
         self
     }
 }
 
+/// Sets the parameter `key` to `val` in a list of parameters. A parameter that
+/// is already set keeps its position and only has its value replaced.
+fn set_param<K: PartialEq>(params: &mut Vec<(K, String)>, key: K, val: String) {
+    match params.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, v)) => *v = val,
+        None => params.push((key, val)),
+    }
+}
+
+/// Rounds `val` to 4 decimal places, so that floating point noise does not
+/// end up in the URL.
+fn round(val: f64) -> f64 {
+    (val * 10_000.0).round() / 10_000.0
+}
+
+/// Computes the `s` parameter for a URL: the hex encoded MD5 digest of the
+/// secure token, the path, and the query string (if any).
+fn signature(token: &str, path: &str, qs: &str) -> String {
+    let base = if qs.is_empty() {
+        format!("{}{}", token, path)
+    } else {
+        format!("{}{}?{}", token, path, qs)
+    };
+
+    format!("{:x}", md5::compute(base))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn works() {
-        let url = ImgixUrl::build("https://foo.com")
+        let url = ImgixUrl::build("foo.com", "cat.jpg")
             .blur(40)
             .q(40)
             .w(300)
@@ -250,6 +1168,203 @@ mod tests {
             .ar(9, 1)
             .finish();
 
-        assert_eq!(url, "https://foo.com/?blur=40&q=40&w=300&fit=crop&ar=9:1");
+        assert_eq!(
+            url,
+            "https://foo.com/cat.jpg?blur=40&q=40&w=300&fit=crop&ar=9:1"
+        );
+    }
+
+    #[test]
+    fn separates_domain_and_path() {
+        assert_eq!(
+            ImgixUrl::build("http://foo.com/", "/photos/cat dog.jpg").finish(),
+            "http://foo.com/photos/cat%20dog.jpg"
+        );
+        assert_eq!(ImgixUrl::build("foo.com", "").finish(), "https://foo.com/");
+    }
+
+    #[test]
+    fn parses() {
+        let url = ImgixUrl::build("foo.com", "photos/cat dog.jpg")
+            .w(300)
+            .fit(ImgixFit::Crop)
+            .ar(16, 9)
+            .crop(ImgixCrop::build().top().faces().finish())
+            .auto(ImgixAuto::build().format().compress().finish())
+            .rect(ImgixRect {
+                x: Direction::X(X::Center),
+                y: Direction::Number(20),
+                w: 100,
+                h: 50,
+            })
+            .cs(ImgixColorSpace::Strip)
+            .ch(ImgixClientHints::build().width().finish())
+            .param("custom", "a&b")
+            .finish();
+
+        assert_eq!(ImgixUrl::parse(&url).unwrap().finish(), url);
+
+        for url in &[
+            "https://foo.com/cat.jpg?dpr=1.5&w=0.5&q=75.5",
+            "https://foo.com/cat.jpg?ar=1.5:1&fit=crop&bg=%23FFF",
+            "https://foo.com/cat.jpg?crop=focalpoint&fp-x=0.50&fp-debug=true",
+            "https://foo.com/cat.jpg?lossless=true&fm=webp",
+            "https://foo.com/cat.jpg?rot=45.0&bg=fff",
+            "https://foo.com/cat.jpg?trim=color&trim-color=FFF&trim-tol=2.50",
+            "https://foo.com/cat.jpg?mask=corners&corner-radius=8,8,0,0&mask-bg=fff",
+            "https://foo.com/cat.jpg?fit=fill&fill=gen&fill-gen-prompt=a%20beach&fill-gen-seed=7&fill-gen-fallback=false",
+            "https://foo.com/a%2Fb.jpg",
+            "https://foo.com/cat.jpg?w=300&fit=crop&crop=top%2Cleft",
+            "https://foo.com/cat.jpg?txt=a+b",
+        ] {
+            assert_eq!(ImgixUrl::parse(url).unwrap().finish(), *url);
+        }
+        assert_eq!(
+            ImgixUrl::parse("https://foo.com/a%2Fb.jpg?txt=a+b")
+                .unwrap()
+                .path("c d.jpg")
+                .param("txt", "a b")
+                .finish(),
+            "https://foo.com/c%20d.jpg?txt=a%20b"
+        );
+        assert_eq!(
+            ImgixUrl::parse("foo.com/cat.jpg").unwrap_err(),
+            ImgixError::InvalidUrl("foo.com/cat.jpg".into())
+        );
+        assert_eq!(
+            ImgixUrl::parse("https://foo.com/cat.jpg?fit=cover").unwrap_err(),
+            ImgixError::invalid_value("fit", "cover")
+        );
+        let url = ImgixUrl::build("foo.com", "cat.jpg")
+            .mask(ImgixMask::Image("https://foo.com/star.png".into()))
+            .try_finish()
+            .unwrap();
+        assert_eq!(ImgixUrl::parse(&url).unwrap().finish(), url);
+        assert!(ImgixUrl::build("foo.com", "cat.jpg")
+            .mask(ImgixMask::Image("masks/star.png".into()))
+            .try_finish()
+            .is_err());
+        assert_eq!(
+            ImgixUrl::parse("https://foo.com/cat.jpg?mask=star").unwrap_err(),
+            ImgixError::invalid_value("mask", "star")
+        );
+        assert_eq!(
+            ImgixUrl::parse("https://foo.com/cat.jpg?w=wide").unwrap_err(),
+            ImgixError::invalid_value("w", "wide")
+        );
+        assert_eq!(
+            ImgixUrl::parse("https://foo.com/cat.jpg?fill-gen-fallback=1").unwrap_err(),
+            ImgixError::invalid_value("fill-gen-fallback", "1")
+        );
+    }
+
+    #[test]
+    fn generates_srcset() {
+        let srcset = ImgixUrl::build("foo.com", "cat.jpg")
+            .ar(16, 9)
+            .fit(ImgixFit::Crop)
+            .srcset(ImgixSrcSet::build().min_width(7000).finish());
+
+        assert_eq!(
+            srcset,
+            "https://foo.com/cat.jpg?ar=16:9&fit=crop&w=7000 7000w,\n\
+             https://foo.com/cat.jpg?ar=16:9&fit=crop&w=8120 8120w,\n\
+             https://foo.com/cat.jpg?ar=16:9&fit=crop&w=8192 8192w"
+        );
+
+        let srcset = ImgixUrl::build("foo.com", "cat.jpg")
+            .h(100)
+            .ar(1, 1)
+            .q(90)
+            .dpr(2)
+            .srcset(ImgixSrcSet::build().finish());
+
+        assert_eq!(
+            srcset,
+            "https://foo.com/cat.jpg?h=100&ar=1:1&q=90&dpr=1 1x,\n\
+             https://foo.com/cat.jpg?h=100&ar=1:1&q=90&dpr=2 2x,\n\
+             https://foo.com/cat.jpg?h=100&ar=1:1&q=90&dpr=3 3x,\n\
+             https://foo.com/cat.jpg?h=100&ar=1:1&q=90&dpr=4 4x,\n\
+             https://foo.com/cat.jpg?h=100&ar=1:1&q=90&dpr=5 5x"
+        );
+    }
+
+    #[test]
+    fn stylizes() {
+        let url = ImgixUrl::build("foo.com", "cat.jpg")
+            .duotone(
+                ImgixColor::named("navy").unwrap(),
+                ImgixColor::rgb(250, 128, 114),
+            )
+            .duotone_alpha(50)
+            .monochrome(ImgixColor::hex("#44c").unwrap())
+            .try_finish()
+            .unwrap();
+
+        assert_eq!(
+            url,
+            "https://foo.com/cat.jpg?duotone=navy,FA8072&duotone-alpha=50&monochrome=44c"
+        );
+        assert_eq!(ImgixUrl::parse(&url).unwrap().finish(), url);
+        assert_eq!(
+            ImgixUrl::build("foo.com", "cat.jpg")
+                .rot(0.1 + 0.2)
+                .finish(),
+            "https://foo.com/cat.jpg?rot=0.3"
+        );
+        assert_eq!(
+            ImgixUrl::build("foo.com", "cat.jpg")
+                .fp_z(0.1 + 0.2)
+                .finish(),
+            "https://foo.com/cat.jpg?fp-z=0.3"
+        );
+        assert!(ImgixUrl::build("foo.com", "cat.jpg")
+            .fit(ImgixFit::Crop)
+            .crop(ImgixCrop::build().focalpoint().finish())
+            .fp_pixel(10, 10, 0, 100)
+            .try_finish()
+            .is_err());
+        assert!(ImgixUrl::build("foo.com", "cat.jpg")
+            .sepia(101)
+            .try_finish()
+            .is_err());
+    }
+
+    #[test]
+    fn replaces_params() {
+        let url = ImgixUrl::build("foo.com", "cat.jpg")
+            .w(300)
+            .q(40)
+            .param("w", "600")
+            .remove("q")
+            .q(80)
+            .finish();
+
+        assert_eq!(url, "https://foo.com/cat.jpg?w=600&q=80");
+    }
+
+    #[test]
+    fn is_owned() {
+        fn assert_owned<T: Clone + Eq + std::hash::Hash + Send + Sync + 'static>() {}
+
+        assert_owned::<ImgixUrlBuilder>();
+        assert_owned::<ImgixAuto>();
+        assert_owned::<ImgixClientHints>();
+        assert_owned::<ImgixColorSpace>();
+        assert_owned::<ImgixCrop>();
+        assert_owned::<ImgixFit>();
+        assert_owned::<ImgixRect>();
+        assert_owned::<Direction>();
+    }
+
+    #[test]
+    fn signs() {
+        let url = ImgixUrl::build("foo.com", "photos/cat.jpg")
+            .w(300)
+            .secure_token("FOO123bar")
+            .finish();
+        let s = format!("{:x}", md5::compute("FOO123bar/photos/cat.jpg?w=300"));
+
+        assert_eq!(url, format!("https://foo.com/photos/cat.jpg?w=300&s={}", s));
     }
 }
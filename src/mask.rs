@@ -0,0 +1,78 @@
+use crate::{error::ImgixError, ImgixRadius};
+
+/// The `mask` parameter defines the shape of the image, making the area
+/// outside of it transparent (or filled with `mask-bg`).
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixColor, ImgixMask, ImgixRadius, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "avatar.jpg")
+///     .mask(ImgixMask::Ellipse)
+///     .mask_bg(ImgixColor::named("white").unwrap())
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/avatar.jpg?mask=ellipse&mask-bg=white");
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "card.jpg")
+///     .mask(ImgixMask::Corners(ImgixRadius::All(16)))
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/card.jpg?mask=corners&corner-radius=16");
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/mask) for more info.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixMask {
+    /// Masks the image with an ellipse filling its dimensions, or a circle if
+    /// the image is square.
+    Ellipse,
+
+    /// Rounds the corners of the image with the given radius.
+    Corners(ImgixRadius),
+
+    /// Masks the image with the alpha channel of the image at the given
+    /// absolute `http` or `https` URL.
+    Image(String),
+}
+
+impl ImgixMask {
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ImgixMask::Ellipse => vec![("mask", "ellipse".into())],
+            ImgixMask::Corners(radius) => vec![
+                ("mask", "corners".into()),
+                ("corner-radius", radius.to_string()),
+            ],
+            ImgixMask::Image(url) => vec![("mask", url.clone())],
+        }
+    }
+}
+
+/// Checks that a `mask` value is `ellipse`, `corners` or an absolute `http`
+/// or `https` URL.
+pub(crate) fn check(val: &str) -> Result<(), ImgixError> {
+    let image = val.starts_with("http://") || val.starts_with("https://");
+
+    if image || val == "ellipse" || val == "corners" {
+        Ok(())
+    } else {
+        Err(ImgixError::invalid_value("mask", val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(
+            ImgixMask::Image("https://foo.com/star.png".into()).params(),
+            vec![("mask", "https://foo.com/star.png".to_string())]
+        );
+        assert!(check("https://foo.com/star.png").is_ok());
+        assert!(check("masks/star.png").is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use std::{fmt, str::FromStr};
+
+use crate::{error::ImgixError, round};
+
+/// A number for parameters that accept decimal values, such as `trim-md`.
+/// It is stored as written in the URL, so that it can be compared and hashed.
+///
+/// Decimals are rounded to 4 places. Values that are not finite, such as
+/// `f64::NAN`, are rejected by `try_finish()`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::ImgixNumber;
+///
+/// assert_eq!(ImgixNumber::from(0.1 + 0.2).to_string(), "0.3");
+/// assert_eq!(ImgixNumber::from(10).value(), 10.0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImgixNumber(String);
+
+impl ImgixNumber {
+    /// Returns the number as an `f64`.
+    pub fn value(&self) -> f64 {
+        self.0.parse().unwrap_or(f64::NAN)
+    }
+}
+
+impl From<i32> for ImgixNumber {
+    fn from(val: i32) -> Self {
+        ImgixNumber(val.to_string())
+    }
+}
+
+impl From<f64> for ImgixNumber {
+    fn from(val: f64) -> Self {
+        ImgixNumber(round(val).to_string())
+    }
+}
+
+impl fmt::Display for ImgixNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ImgixNumber {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<f64>() {
+            Ok(val) if val.is_finite() => Ok(ImgixNumber(s.into())),
+            _ => Err(ImgixError::invalid_value("number", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!("2.50".parse::<ImgixNumber>().unwrap().to_string(), "2.50");
+        assert!("inf".parse::<ImgixNumber>().is_err());
+    }
+}
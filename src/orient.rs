@@ -0,0 +1,155 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
+/// The `orient` parameter changes the cardinal orientation of the image,
+/// overriding any orientation stored in its EXIF data.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixFlip, ImgixOrient, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "phone.jpg")
+///     .orient(ImgixOrient::Rotate90)
+///     .flip(ImgixFlip::H)
+///     .finish();
+///
+/// assert_eq!(url, "https://foo.imgix.net/phone.jpg?orient=90&flip=h");
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/rotation/orient) for more
+/// info.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixOrient {
+    /// EXIF orientation 0, which is undefined.
+    Undefined,
+
+    /// EXIF orientation 1, the image as stored.
+    TopLeft,
+
+    /// EXIF orientation 2, flipped horizontally.
+    TopRight,
+
+    /// EXIF orientation 3, rotated 180 degrees.
+    BottomRight,
+
+    /// EXIF orientation 4, flipped vertically.
+    BottomLeft,
+
+    /// EXIF orientation 5, flipped horizontally and rotated 90 degrees
+    /// counter-clockwise.
+    LeftTop,
+
+    /// EXIF orientation 6, rotated 90 degrees clockwise.
+    RightTop,
+
+    /// EXIF orientation 7, flipped horizontally and rotated 90 degrees
+    /// clockwise.
+    RightBottom,
+
+    /// EXIF orientation 8, rotated 90 degrees counter-clockwise.
+    LeftBottom,
+
+    /// Rotates the image 90 degrees counter-clockwise.
+    Rotate90,
+
+    /// Rotates the image 180 degrees.
+    Rotate180,
+
+    /// Rotates the image 270 degrees counter-clockwise.
+    Rotate270,
+}
+
+impl fmt::Display for ImgixOrient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixOrient::Undefined => f.write_str("0"),
+            ImgixOrient::TopLeft => f.write_str("1"),
+            ImgixOrient::TopRight => f.write_str("2"),
+            ImgixOrient::BottomRight => f.write_str("3"),
+            ImgixOrient::BottomLeft => f.write_str("4"),
+            ImgixOrient::LeftTop => f.write_str("5"),
+            ImgixOrient::RightTop => f.write_str("6"),
+            ImgixOrient::RightBottom => f.write_str("7"),
+            ImgixOrient::LeftBottom => f.write_str("8"),
+            ImgixOrient::Rotate90 => f.write_str("90"),
+            ImgixOrient::Rotate180 => f.write_str("180"),
+            ImgixOrient::Rotate270 => f.write_str("270"),
+        }
+    }
+}
+
+impl FromStr for ImgixOrient {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(ImgixOrient::Undefined),
+            "1" => Ok(ImgixOrient::TopLeft),
+            "2" => Ok(ImgixOrient::TopRight),
+            "3" => Ok(ImgixOrient::BottomRight),
+            "4" => Ok(ImgixOrient::BottomLeft),
+            "5" => Ok(ImgixOrient::LeftTop),
+            "6" => Ok(ImgixOrient::RightTop),
+            "7" => Ok(ImgixOrient::RightBottom),
+            "8" => Ok(ImgixOrient::LeftBottom),
+            "90" => Ok(ImgixOrient::Rotate90),
+            "180" => Ok(ImgixOrient::Rotate180),
+            "270" => Ok(ImgixOrient::Rotate270),
+            _ => Err(ImgixError::invalid_value("orient", s)),
+        }
+    }
+}
+
+/// The `flip` parameter flips the image horizontally, vertically, or both.
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/rotation/flip) for more
+/// info.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixFlip {
+    /// Flips the image horizontally.
+    H,
+
+    /// Flips the image vertically.
+    V,
+
+    /// Flips the image both horizontally and vertically.
+    HV,
+}
+
+impl fmt::Display for ImgixFlip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixFlip::H => f.write_str("h"),
+            ImgixFlip::V => f.write_str("v"),
+            ImgixFlip::HV => f.write_str("hv"),
+        }
+    }
+}
+
+impl FromStr for ImgixFlip {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "h" => Ok(ImgixFlip::H),
+            "v" => Ok(ImgixFlip::V),
+            "hv" => Ok(ImgixFlip::HV),
+            _ => Err(ImgixError::invalid_value("flip", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!("6".parse(), Ok(ImgixOrient::RightTop));
+        assert_eq!("8", ImgixOrient::LeftBottom.to_string());
+        assert_eq!("270".parse(), Ok(ImgixOrient::Rotate270));
+        assert!("9".parse::<ImgixOrient>().is_err());
+        assert_eq!("hv", ImgixFlip::HV.to_string());
+    }
+}
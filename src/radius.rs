@@ -0,0 +1,122 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
+/// A corner radius for parameters such as `border-radius`, either the same
+/// for every corner or given per corner.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixColor, ImgixRadius, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .border(4, ImgixColor::named("black").unwrap())
+///     .border_radius(ImgixRadius::Corners {
+///         top_left: 10,
+///         top_right: 10,
+///         bottom_right: 0,
+///         bottom_left: 0,
+///     })
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/cat.jpg?border=4,black&border-radius=10,10,0,0"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixRadius {
+    /// The same radius for every corner, in pixels.
+    All(i32),
+
+    /// A radius for each corner, in pixels.
+    Corners {
+        /// Radius of the top left corner.
+        top_left: i32,
+
+        /// Radius of the top right corner.
+        top_right: i32,
+
+        /// Radius of the bottom right corner.
+        bottom_right: i32,
+
+        /// Radius of the bottom left corner.
+        bottom_left: i32,
+    },
+}
+
+impl ImgixRadius {
+    /// Returns the radius of each corner, clockwise from the top left.
+    pub(crate) fn corners(&self) -> [i32; 4] {
+        match *self {
+            ImgixRadius::All(radius) => [radius; 4],
+            ImgixRadius::Corners {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } => [top_left, top_right, bottom_right, bottom_left],
+        }
+    }
+}
+
+impl fmt::Display for ImgixRadius {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixRadius::All(radius) => write!(f, "{}", radius),
+            ImgixRadius::Corners {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } => write!(
+                f,
+                "{},{},{},{}",
+                top_left, top_right, bottom_right, bottom_left
+            ),
+        }
+    }
+}
+
+impl FromStr for ImgixRadius {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ImgixError::invalid_value("radius", s);
+        let radii = s
+            .split(',')
+            .map(|radius| radius.parse().map_err(|_| invalid()))
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        match radii[..] {
+            [radius] => Ok(ImgixRadius::All(radius)),
+            [top_left, top_right, bottom_right, bottom_left] => Ok(ImgixRadius::Corners {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(ImgixRadius::All(8).to_string(), "8");
+        assert_eq!(
+            "1,2,3,4".parse(),
+            Ok(ImgixRadius::Corners {
+                top_left: 1,
+                top_right: 2,
+                bottom_right: 3,
+                bottom_left: 4,
+            })
+        );
+        assert!("1,2".parse::<ImgixRadius>().is_err());
+    }
+}
@@ -1,5 +1,9 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::ImgixError;
+
 /// Represents a valid direction for the `x` option of `ImgixRect`.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum X {
     /// Equivalent to 0.
     Left,
@@ -12,18 +16,31 @@ pub enum X {
     Right,
 }
 
-impl ToString for X {
-    fn to_string(&self) -> String {
+impl fmt::Display for X {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            X::Left => "left".into(),
-            X::Right => "right".into(),
-            X::Center => "center".into(),
+            X::Left => f.write_str("left"),
+            X::Right => f.write_str("right"),
+            X::Center => f.write_str("center"),
+        }
+    }
+}
+
+impl FromStr for X {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "left" => Ok(X::Left),
+            "right" => Ok(X::Right),
+            "center" => Ok(X::Center),
+            _ => Err(ImgixError::invalid_value("rect", s)),
         }
     }
 }
 
 /// Represents a valid direction for the `y` option of `ImgixRect`.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Y {
     /// Equivalent to 0.
     Top,
@@ -37,19 +54,32 @@ pub enum Y {
     Bottom,
 }
 
-impl ToString for Y {
-    fn to_string(&self) -> String {
+impl fmt::Display for Y {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Y::Top => "top".into(),
-            Y::Middle => "middle".into(),
-            Y::Bottom => "bottom".into(),
+            Y::Top => f.write_str("top"),
+            Y::Middle => f.write_str("middle"),
+            Y::Bottom => f.write_str("bottom"),
+        }
+    }
+}
+
+impl FromStr for Y {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "top" => Ok(Y::Top),
+            "middle" => Ok(Y::Middle),
+            "bottom" => Ok(Y::Bottom),
+            _ => Err(ImgixError::invalid_value("rect", s)),
         }
     }
 }
 
 /// Enum representing a valid directional value for `ImgixRect`. A direction can
 /// be an integer or a valid `X` or `Y` value.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     /// Integer value.
     Number(i32),
@@ -61,16 +91,30 @@ pub enum Direction {
     Y(Y),
 }
 
-impl ToString for Direction {
-    fn to_string(&self) -> String {
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Direction::Number(num) => num.to_string(),
-            Direction::X(x) => x.to_string(),
-            Direction::Y(y) => y.to_string(),
+            Direction::Number(num) => write!(f, "{}", num),
+            Direction::X(x) => write!(f, "{}", x),
+            Direction::Y(y) => write!(f, "{}", y),
         }
     }
 }
 
+impl FromStr for Direction {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(num) = s.parse() {
+            return Ok(Direction::Number(num));
+        }
+
+        s.parse()
+            .map(Direction::X)
+            .or_else(|_| s.parse().map(Direction::Y))
+    }
+}
+
 /// Struct to represent the `rect` URL parameter. Construct the
 /// parameter by calling instantiating it plainly.
 ///
@@ -84,13 +128,13 @@ impl ToString for Direction {
 ///     w: 100,
 ///     h: 50,
 /// };
-/// let url = ImgixUrl::build("https://foo.com")
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
 ///     .rect(rect)
 ///     .finish();
 ///
-/// assert_eq!(url, "https://foo.com/?rect=300,bottom,100,50");
+/// assert_eq!(url, "https://foo.imgix.net/cat.jpg?rect=300,bottom,100,50");
 /// ```
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ImgixRect {
     /// Can take an integer or Left, Center, and Right options.
     pub x: Direction,
@@ -105,15 +149,28 @@ pub struct ImgixRect {
     pub h: i32,
 }
 
-impl ToString for ImgixRect {
-    fn to_string(&self) -> String {
-        vec![
-            self.x.to_string(),
-            self.y.to_string(),
-            self.w.to_string(),
-            self.h.to_string(),
-        ]
-        .join(",")
+impl fmt::Display for ImgixRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{},{}", self.x, self.y, self.w, self.h)
+    }
+}
+
+impl FromStr for ImgixRect {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ImgixError::invalid_value("rect", s);
+        let parts: Vec<&str> = s.split(',').collect();
+
+        match parts[..] {
+            [x, y, w, h] => Ok(ImgixRect {
+                x: x.parse().map_err(|_| invalid())?,
+                y: y.parse().map_err(|_| invalid())?,
+                w: w.parse().map_err(|_| invalid())?,
+                h: h.parse().map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
     }
 }
 
@@ -0,0 +1,399 @@
+use std::fmt;
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    mask, validate, Direction, ImgixAdjust, ImgixAlign, ImgixAuto, ImgixBlend, ImgixBlendMode,
+    ImgixChromaSub, ImgixClientHints, ImgixColor, ImgixColorSpace, ImgixCrop, ImgixDetail,
+    ImgixError, ImgixFill, ImgixFit, ImgixFlip, ImgixFontFamily, ImgixFontStyle, ImgixFormat,
+    ImgixMask, ImgixNumber, ImgixOrient, ImgixRadius, ImgixRect, ImgixText, ImgixTextClip,
+    ImgixTrim, ImgixUrl, ImgixUrlBuilder, ImgixWatermark, X, Y,
+};
+
+/// Implements `Serialize` and `Deserialize` for parameter types in terms of
+/// their `Display` and `FromStr` impls, so they use Imgix's own string values.
+macro_rules! serde_via_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer)?
+                        .parse()
+                        .map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+serde_via_str!(
+    ImgixAlign,
+    ImgixAuto,
+    ImgixBlendMode,
+    ImgixChromaSub,
+    ImgixClientHints,
+    ImgixColor,
+    ImgixColorSpace,
+    ImgixCrop,
+    ImgixFit,
+    ImgixFlip,
+    ImgixFontFamily,
+    ImgixFontStyle,
+    ImgixFormat,
+    ImgixNumber,
+    ImgixOrient,
+    ImgixRadius,
+    ImgixRect,
+    ImgixTextClip,
+    Direction,
+    X,
+    Y,
+);
+
+/// Implements `Serialize` and `Deserialize` for types that set several
+/// parameters at once, as a map of those parameters. Parameters that the type
+/// does not produce are rejected when deserializing.
+macro_rules! serde_via_params {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let params = self
+                        .params()
+                        .iter()
+                        .map(|(key, val)| (key.to_string(), val.clone()))
+                        .collect();
+
+                    Params(params).serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let params = Params::deserialize(deserializer)?.0;
+                    let value = Self::from_params(&params).map_err(de::Error::custom)?;
+
+                    if let Some((key, _)) = params
+                        .iter()
+                        .find(|(key, _)| value.params().iter().all(|(k, _)| k != key))
+                    {
+                        return Err(de::Error::custom(format!("unexpected parameter `{}`", key)));
+                    }
+
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+serde_via_params!(
+    ImgixAdjust,
+    ImgixBlend,
+    ImgixDetail,
+    ImgixFill,
+    ImgixMask,
+    ImgixText,
+    ImgixTrim,
+    ImgixWatermark,
+);
+
+/// Implements `from_params()` for group builders, which set any of a fixed
+/// list of parameters. Values are checked the same way `try_finish()` does.
+macro_rules! group_from_params {
+    ($($ty:ty => $keys:expr),* $(,)?) => {
+        $(
+            impl $ty {
+                fn from_params(params: &[(String, String)]) -> Result<Self, ImgixError> {
+                    validate::validate(params)?;
+
+                    let mut group = Self::build();
+                    for (key, val) in params {
+                        let keys: &[&'static str] = $keys;
+                        if let Some(key) = keys.iter().find(|k| *k == key) {
+                            group.set(key, val.clone());
+                        }
+                    }
+
+                    Ok(group)
+                }
+            }
+        )*
+    };
+}
+
+group_from_params!(
+    ImgixAdjust => &[
+        "bri", "con", "exp", "gam", "high", "hue", "invert", "sat", "shad", "sharp", "usm",
+        "usmrad", "vib",
+    ],
+    ImgixBlend => &[
+        "blend", "blend64", "blend-align", "blend-alpha", "blend-crop", "blend-fit", "blend-h",
+        "blend-mode", "blend-pad", "blend-size", "blend-w", "blend-x", "blend-y",
+    ],
+    ImgixDetail => &["nr", "nrs", "sharp", "usm", "usmrad"],
+    ImgixText => &[
+        "txt", "txt64", "txt-align", "txt-clip", "txt-color", "txt-fit", "txt-font", "txt-lead",
+        "txt-line", "txt-line-color", "txt-pad", "txt-shad", "txt-size", "txt-track",
+        "txt-width",
+    ],
+    ImgixWatermark => &[
+        "mark64", "mark-align", "mark-alpha", "mark-base", "mark-fit", "mark-h", "mark-pad",
+        "mark-scale", "mark-tile", "mark-w", "mark-x", "mark-y",
+    ],
+);
+
+/// Returns the value of the parameter named `key`, if it is set.
+fn find<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+impl ImgixFill {
+    /// Creates the fill from its parameters, as returned by `params()`.
+    fn from_params(params: &[(String, String)]) -> Result<Self, ImgixError> {
+        let get = |key: &str| find(params, key);
+        let seed = get("fill-gen-seed")
+            .map(|val| {
+                val.parse()
+                    .map_err(|_| ImgixError::invalid_value("fill-gen-seed", val))
+            })
+            .transpose()?;
+        let fallback = get("fill-gen-fallback")
+            .map(|val| {
+                val.parse()
+                    .map_err(|_| ImgixError::invalid_value("fill-gen-fallback", val))
+            })
+            .transpose()?;
+
+        match get("fill") {
+            Some("solid") => match get("fill-color") {
+                Some(color) => Ok(ImgixFill::Solid(color.parse()?)),
+                None => Err(ImgixError::MissingParam {
+                    param: "fill".into(),
+                    requires: "fill-color".into(),
+                }),
+            },
+            Some("blur") => Ok(ImgixFill::Blur),
+            Some("gen") => Ok(ImgixFill::Generative {
+                prompt: get("fill-gen-prompt").map(String::from),
+                seed,
+                fallback,
+            }),
+            Some(val) => Err(ImgixError::invalid_value("fill", val)),
+            None => Err(ImgixError::MissingParam {
+                param: "fill-*".into(),
+                requires: "fill".into(),
+            }),
+        }
+    }
+}
+
+impl ImgixMask {
+    /// Creates the mask from its parameters, as returned by `params()`.
+    fn from_params(params: &[(String, String)]) -> Result<Self, ImgixError> {
+        let get = |key: &str| find(params, key);
+
+        match get("mask") {
+            Some("ellipse") => Ok(ImgixMask::Ellipse),
+            Some("corners") => match get("corner-radius") {
+                Some(radius) => Ok(ImgixMask::Corners(radius.parse()?)),
+                None => Err(ImgixError::MissingParam {
+                    param: "mask".into(),
+                    requires: "corner-radius".into(),
+                }),
+            },
+            Some(url) => {
+                mask::check(url)?;
+                Ok(ImgixMask::Image(url.into()))
+            }
+            None => Err(ImgixError::MissingParam {
+                param: "corner-radius".into(),
+                requires: "mask".into(),
+            }),
+        }
+    }
+}
+
+impl ImgixTrim {
+    /// Creates the trim from its parameters, as returned by `params()`.
+    fn from_params(params: &[(String, String)]) -> Result<Self, ImgixError> {
+        let get = |key: &str| find(params, key);
+        let number = |key: &str| {
+            get(key)
+                .map(|val| val.parse().map_err(|_| ImgixError::invalid_value(key, val)))
+                .transpose()
+        };
+
+        match get("trim") {
+            Some("auto") => Ok(ImgixTrim::Auto {
+                md: number("trim-md")?,
+                sd: number("trim-sd")?,
+            }),
+            Some("color") => Ok(ImgixTrim::Color {
+                color: get("trim-color").map(str::parse).transpose()?,
+                tol: number("trim-tol")?,
+            }),
+            Some(val) => Err(ImgixError::invalid_value("trim", val)),
+            None => Err(ImgixError::MissingParam {
+                param: "trim-*".into(),
+                requires: "trim".into(),
+            }),
+        }
+    }
+}
+
+/// Serialized form of an `ImgixUrlBuilder`. The secure token is deliberately
+/// left out.
+#[derive(Serialize, Deserialize)]
+struct Repr {
+    domain: String,
+    #[serde(default)]
+    path: String,
+    #[serde(default)]
+    params: Params,
+}
+
+/// Parameters serialized as a map, keeping their order.
+#[derive(Default)]
+struct Params(Vec<(String, String)>);
+
+impl Serialize for Params {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, val) in &self.0 {
+            map.serialize_entry(key, val)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Params {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ParamsVisitor;
+
+        impl<'de> Visitor<'de> for ParamsVisitor {
+            type Value = Params;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of Imgix parameters")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Params, A::Error> {
+                let mut params = Vec::new();
+                while let Some(entry) = access.next_entry()? {
+                    params.push(entry);
+                }
+                Ok(Params(params))
+            }
+        }
+
+        deserializer.deserialize_map(ParamsVisitor)
+    }
+}
+
+/// Serializes the domain, path and parameters of the builder. The secure token
+/// is not serialized.
+impl Serialize for ImgixUrlBuilder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            domain: self.domain.clone(),
+            path: self.path.clone(),
+            params: Params(self.params.clone()),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a builder, rejecting invalid values of known parameters the
+/// same way `ImgixUrl::parse()` does.
+impl<'de> Deserialize<'de> for ImgixUrlBuilder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        let mut builder = ImgixUrl::build(&repr.domain, &repr.path);
+
+        for (key, val) in &repr.params.0 {
+            builder.set_typed(key, val).map_err(de::Error::custom)?;
+        }
+
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ImgixSrcSet;
+
+    #[test]
+    fn works() {
+        let rect: ImgixRect = serde_json::from_str("\"300,bottom,100,50\"").unwrap();
+        assert_eq!(rect.y, Direction::Y(Y::Bottom));
+        assert_eq!(
+            serde_json::to_string(&ImgixColorSpace::AdobeRGB1998).unwrap(),
+            "\"adobergb1998\""
+        );
+
+        let json = r#"{"domain":"https://foo.com","path":"cat.jpg","params":{"w":"300","fit":"facearea"}}"#;
+        let builder: ImgixUrlBuilder = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            builder.finish(),
+            "https://foo.com/cat.jpg?w=300&fit=facearea"
+        );
+        assert_eq!(serde_json::to_string(&builder).unwrap(), json);
+
+        let trim = ImgixTrim::Color {
+            color: None,
+            tol: Some(10.into()),
+        };
+        let json = r#"{"trim":"color","trim-tol":"10"}"#;
+        assert_eq!(serde_json::to_string(&trim).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ImgixTrim>(json).unwrap(), trim);
+        let json = r#"{"trim":"color","trim-md":"10"}"#;
+        assert!(serde_json::from_str::<ImgixTrim>(json).is_err());
+
+        let mask = ImgixMask::Corners(ImgixRadius::All(8));
+        let json = r#"{"mask":"corners","corner-radius":"8"}"#;
+        assert_eq!(serde_json::to_string(&mask).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ImgixMask>(json).unwrap(), mask);
+        assert!(serde_json::from_str::<ImgixMask>(r#"{"mask":"star"}"#).is_err());
+
+        let fill = ImgixFill::Generative {
+            prompt: Some("beach".into()),
+            seed: Some(7),
+            fallback: None,
+        };
+        let json = r#"{"fill":"gen","fill-gen-prompt":"beach","fill-gen-seed":"7"}"#;
+        assert_eq!(serde_json::to_string(&fill).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ImgixFill>(json).unwrap(), fill);
+
+        let adjust = ImgixAdjust::build().bri(20).usmrad(1.5).finish();
+        let json = r#"{"bri":"20","usmrad":"1.5"}"#;
+        assert_eq!(serde_json::to_string(&adjust).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ImgixAdjust>(json).unwrap(), adjust);
+        assert!(serde_json::from_str::<ImgixAdjust>(r#"{"bri":"200"}"#).is_err());
+        assert!(serde_json::from_str::<ImgixAdjust>(r#"{"nr":"20"}"#).is_err());
+
+        let srcset = ImgixSrcSet::build().min_width(200).finish();
+        let json = serde_json::to_string(&srcset).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ImgixSrcSet>(&json).unwrap().widths(),
+            srcset.widths()
+        );
+
+        let json = r#"{"domain":"foo.com","params":{"fit":"cover"}}"#;
+        assert!(serde_json::from_str::<ImgixUrlBuilder>(json).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+/// Builder to configure the `srcset` generated by `ImgixUrlBuilder::srcset()`.
+/// Begin constructing the options by calling `build()`.
+///
+/// For fluid-width images, candidate widths start at `min_width` and grow by twice the `tolerance` at
+/// each step until `max_width` is reached. The defaults produce Imgix's
+/// standard width ladder, from 100 to 8192 pixels with an 8% tolerance.
+///
+/// For fixed-size images, where `w` is set (or `h` along with `ar`), a
+/// candidate is generated for each pixel density from `1x` to `5x` instead. By
+/// default, `q` is lowered as the density increases, unless it is set on the
+/// builder.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixUrl, ImgixSrcSet};
+///
+/// let srcset = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .q(80)
+///     .srcset(ImgixSrcSet::build().min_width(100).max_width(200).finish());
+///
+/// assert_eq!(
+///     srcset,
+///     "https://foo.imgix.net/cat.jpg?q=80&w=100 100w,\n\
+///      https://foo.imgix.net/cat.jpg?q=80&w=116 116w,\n\
+///      https://foo.imgix.net/cat.jpg?q=80&w=135 135w,\n\
+///      https://foo.imgix.net/cat.jpg?q=80&w=156 156w,\n\
+///      https://foo.imgix.net/cat.jpg?q=80&w=181 181w,\n\
+///      https://foo.imgix.net/cat.jpg?q=80&w=200 200w"
+/// );
+///
+/// let srcset = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .w(100)
+///     .srcset(ImgixSrcSet::build().qualities([80, 60, 40, 30, 20]).finish());
+///
+/// assert_eq!(
+///     srcset,
+///     "https://foo.imgix.net/cat.jpg?w=100&dpr=1&q=80 1x,\n\
+///      https://foo.imgix.net/cat.jpg?w=100&dpr=2&q=60 2x,\n\
+///      https://foo.imgix.net/cat.jpg?w=100&dpr=3&q=40 3x,\n\
+///      https://foo.imgix.net/cat.jpg?w=100&dpr=4&q=30 4x,\n\
+///      https://foo.imgix.net/cat.jpg?w=100&dpr=5&q=20 5x"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ImgixSrcSet {
+    min_width: i32,
+    max_width: i32,
+    tolerance: f64,
+    variable_quality: bool,
+    qualities: [i32; 5],
+}
+
+impl Default for ImgixSrcSet {
+    fn default() -> Self {
+        Self {
+            min_width: 100,
+            max_width: 8192,
+            tolerance: 0.08,
+            variable_quality: true,
+            qualities: [75, 50, 35, 23, 20],
+        }
+    }
+}
+
+impl ImgixSrcSet {
+    /// Starts building the `srcset` options, with Imgix's defaults.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the options and returns the final
+    /// `ImgixSrcSet` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// The smallest candidate width. Defaults to 100, and values below 1 are
+    /// treated as 1.
+    pub fn min_width(&mut self, val: i32) -> &mut Self {
+        self.min_width = val;
+        self
+    }
+
+    /// The largest candidate width. Defaults to 8192, and values below
+    /// `min_width` are treated as `min_width`.
+    pub fn max_width(&mut self, val: i32) -> &mut Self {
+        self.max_width = val;
+        self
+    }
+
+    /// The tolerated difference in width between the requested size and the
+    /// nearest candidate, as a fraction. Defaults to 0.08, and values below
+    /// 0.01 are treated as 0.01.
+    pub fn tolerance(&mut self, val: f64) -> &mut Self {
+        self.tolerance = val;
+        self
+    }
+
+    /// Whether to lower `q` as the pixel density of fixed-size candidates
+    /// increases. Defaults to `true`. Has no effect if `q` is set on the
+    /// builder.
+    pub fn variable_quality(&mut self, val: bool) -> &mut Self {
+        self.variable_quality = val;
+        self
+    }
+
+    /// The `q` values to use for the `1x` to `5x` candidates of fixed-size
+    /// images. Defaults to Imgix's recommended `[75, 50, 35, 23, 20]`.
+    pub fn qualities(&mut self, val: [i32; 5]) -> &mut Self {
+        self.qualities = val;
+        self
+    }
+
+    /// Returns the `q` value for the candidate at `dpr`, if variable quality
+    /// is enabled.
+    pub(crate) fn quality(&self, dpr: i32) -> Option<i32> {
+        if self.variable_quality {
+            self.qualities.get(dpr as usize - 1).copied()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the candidate widths, in ascending order and without
+    /// duplicates.
+    pub(crate) fn widths(&self) -> Vec<i32> {
+        let min_width = self.min_width.max(1);
+        let max_width = self.max_width.max(min_width);
+        let step = 1.0 + self.tolerance.max(0.01) * 2.0;
+        let max = f64::from(max_width);
+        let mut widths = Vec::new();
+        let mut width = f64::from(min_width);
+
+        while width < max {
+            widths.push(width.round() as i32);
+            width *= step;
+        }
+        widths.push(max_width);
+        widths.dedup();
+
+        widths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let widths = ImgixSrcSet::build().finish().widths();
+
+        assert_eq!(
+            widths,
+            vec![
+                100, 116, 135, 156, 181, 210, 244, 283, 328, 380, 441, 512, 594, 689, 799, 927,
+                1075, 1247, 1446, 1678, 1946, 2257, 2619, 3038, 3524, 4087, 4741, 5500, 6380, 7401,
+                8192
+            ]
+        );
+        assert_eq!(
+            ImgixSrcSet::build()
+                .min_width(0)
+                .max_width(3)
+                .finish()
+                .widths(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            ImgixSrcSet::build()
+                .min_width(500)
+                .max_width(-1)
+                .finish()
+                .widths(),
+            vec![500]
+        );
+        assert_eq!(ImgixSrcSet::build().finish().quality(3), Some(35));
+        assert_eq!(
+            ImgixSrcSet::build()
+                .variable_quality(false)
+                .finish()
+                .quality(3),
+            None
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use std::{fmt, str::FromStr};
+
+use crate::{encoding, error::ImgixError, set_param, ImgixAlign, ImgixColor};
+
+/// Font families available to the `txt-font` parameter.
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/text/txt-font) for more
+/// info.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixFontFamily {
+    /// The default sans-serif font.
+    SansSerif,
+
+    /// The default serif font.
+    Serif,
+
+    /// The default monospace font.
+    Monospace,
+
+    /// American Typewriter.
+    AmericanTypewriter,
+
+    /// Avenir.
+    Avenir,
+
+    /// Avenir Next.
+    AvenirNext,
+
+    /// Baskerville.
+    Baskerville,
+
+    /// Copperplate.
+    Copperplate,
+
+    /// Courier.
+    Courier,
+
+    /// Didot.
+    Didot,
+
+    /// Futura.
+    Futura,
+
+    /// Georgia.
+    Georgia,
+
+    /// Gill Sans.
+    GillSans,
+
+    /// Helvetica.
+    Helvetica,
+
+    /// Helvetica Neue.
+    HelveticaNeue,
+
+    /// Hoefler Text.
+    HoeflerText,
+
+    /// Menlo.
+    Menlo,
+
+    /// Optima.
+    Optima,
+
+    /// Palatino.
+    Palatino,
+
+    /// Papyrus.
+    Papyrus,
+
+    /// Times New Roman.
+    TimesNewRoman,
+
+    /// Verdana.
+    Verdana,
+}
+
+impl ImgixFontFamily {
+    /// Returns whether the family is available in `style`.
+    pub fn supports(self, style: ImgixFontStyle) -> bool {
+        use ImgixFontFamily::*;
+        use ImgixFontStyle::*;
+
+        match (self, style) {
+            (_, Regular) => true,
+            (AmericanTypewriter, Bold) | (Copperplate, Bold) => true,
+            (AmericanTypewriter, _) | (Copperplate, _) | (Papyrus, _) => false,
+            (Futura, BoldItalic) => false,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for ImgixFontFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixFontFamily::SansSerif => f.write_str("sans-serif"),
+            ImgixFontFamily::Serif => f.write_str("serif"),
+            ImgixFontFamily::Monospace => f.write_str("monospace"),
+            ImgixFontFamily::AmericanTypewriter => f.write_str("American Typewriter"),
+            ImgixFontFamily::Avenir => f.write_str("Avenir"),
+            ImgixFontFamily::AvenirNext => f.write_str("Avenir Next"),
+            ImgixFontFamily::Baskerville => f.write_str("Baskerville"),
+            ImgixFontFamily::Copperplate => f.write_str("Copperplate"),
+            ImgixFontFamily::Courier => f.write_str("Courier"),
+            ImgixFontFamily::Didot => f.write_str("Didot"),
+            ImgixFontFamily::Futura => f.write_str("Futura"),
+            ImgixFontFamily::Georgia => f.write_str("Georgia"),
+            ImgixFontFamily::GillSans => f.write_str("Gill Sans"),
+            ImgixFontFamily::Helvetica => f.write_str("Helvetica"),
+            ImgixFontFamily::HelveticaNeue => f.write_str("Helvetica Neue"),
+            ImgixFontFamily::HoeflerText => f.write_str("Hoefler Text"),
+            ImgixFontFamily::Menlo => f.write_str("Menlo"),
+            ImgixFontFamily::Optima => f.write_str("Optima"),
+            ImgixFontFamily::Palatino => f.write_str("Palatino"),
+            ImgixFontFamily::Papyrus => f.write_str("Papyrus"),
+            ImgixFontFamily::TimesNewRoman => f.write_str("Times New Roman"),
+            ImgixFontFamily::Verdana => f.write_str("Verdana"),
+        }
+    }
+}
+
+impl FromStr for ImgixFontFamily {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sans-serif" => Ok(ImgixFontFamily::SansSerif),
+            "serif" => Ok(ImgixFontFamily::Serif),
+            "monospace" => Ok(ImgixFontFamily::Monospace),
+            "American Typewriter" => Ok(ImgixFontFamily::AmericanTypewriter),
+            "Avenir" => Ok(ImgixFontFamily::Avenir),
+            "Avenir Next" => Ok(ImgixFontFamily::AvenirNext),
+            "Baskerville" => Ok(ImgixFontFamily::Baskerville),
+            "Copperplate" => Ok(ImgixFontFamily::Copperplate),
+            "Courier" => Ok(ImgixFontFamily::Courier),
+            "Didot" => Ok(ImgixFontFamily::Didot),
+            "Futura" => Ok(ImgixFontFamily::Futura),
+            "Georgia" => Ok(ImgixFontFamily::Georgia),
+            "Gill Sans" => Ok(ImgixFontFamily::GillSans),
+            "Helvetica" => Ok(ImgixFontFamily::Helvetica),
+            "Helvetica Neue" => Ok(ImgixFontFamily::HelveticaNeue),
+            "Hoefler Text" => Ok(ImgixFontFamily::HoeflerText),
+            "Menlo" => Ok(ImgixFontFamily::Menlo),
+            "Optima" => Ok(ImgixFontFamily::Optima),
+            "Palatino" => Ok(ImgixFontFamily::Palatino),
+            "Papyrus" => Ok(ImgixFontFamily::Papyrus),
+            "Times New Roman" => Ok(ImgixFontFamily::TimesNewRoman),
+            "Verdana" => Ok(ImgixFontFamily::Verdana),
+            _ => Err(ImgixError::invalid_value("txt-font", s)),
+        }
+    }
+}
+
+/// Font styles available to the `txt-font` parameter. Not every family is
+/// available in every style, see `ImgixFontFamily::supports()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixFontStyle {
+    /// The regular weight, upright.
+    Regular,
+
+    /// Bold weight.
+    Bold,
+
+    /// Italic.
+    Italic,
+
+    /// Bold weight, italic.
+    BoldItalic,
+}
+
+/// Which part of the text is removed when it does not fit, for the `txt-clip`
+/// parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixTextClip {
+    /// Clips the start of the text.
+    Start,
+
+    /// Clips the middle of the text.
+    Middle,
+
+    /// **Default**. Clips the end of the text.
+    End,
+}
+
+impl fmt::Display for ImgixFontStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixFontStyle::Regular => f.write_str("regular"),
+            ImgixFontStyle::Bold => f.write_str("bold"),
+            ImgixFontStyle::Italic => f.write_str("italic"),
+            ImgixFontStyle::BoldItalic => f.write_str("bold,italic"),
+        }
+    }
+}
+
+impl FromStr for ImgixFontStyle {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regular" => Ok(ImgixFontStyle::Regular),
+            "bold" => Ok(ImgixFontStyle::Bold),
+            "italic" => Ok(ImgixFontStyle::Italic),
+            "bold,italic" => Ok(ImgixFontStyle::BoldItalic),
+            _ => Err(ImgixError::invalid_value("txt-font", s)),
+        }
+    }
+}
+
+impl fmt::Display for ImgixTextClip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixTextClip::Start => f.write_str("start"),
+            ImgixTextClip::Middle => f.write_str("middle"),
+            ImgixTextClip::End => f.write_str("end"),
+        }
+    }
+}
+
+impl FromStr for ImgixTextClip {
+    type Err = ImgixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(ImgixTextClip::Start),
+            "middle" => Ok(ImgixTextClip::Middle),
+            "end" => Ok(ImgixTextClip::End),
+            _ => Err(ImgixError::invalid_value("txt-clip", s)),
+        }
+    }
+}
+
+/// Builder to represent the `txt` parameter and the `txt-*` parameters that
+/// style it. Begin constructing the parameters by calling `build()`.
+///
+/// Text containing non-ASCII characters is sent base64 encoded with `txt64`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixColor, ImgixFontFamily, ImgixFontStyle, ImgixText, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .txt(
+///         ImgixText::build()
+///             .text("Hello, world")
+///             .font(ImgixFontFamily::HelveticaNeue, ImgixFontStyle::Bold)
+///             .size(48)
+///             .color(ImgixColor::named("white").unwrap())
+///             .finish(),
+///     )
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/cat.jpg?txt=Hello,%20world&txt-font=Helvetica%20Neue,bold\
+///      &txt-size=48&txt-color=white"
+/// );
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/text) for more info.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixText {
+    params: Vec<(&'static str, String)>,
+}
+
+impl ImgixText {
+    /// Starts building the text parameters. Returns an `ImgixText` to specify
+    /// the text and its styling.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the text parameters and returns the final
+    /// `ImgixText` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> &[(&'static str, String)] {
+        &self.params
+    }
+
+    pub(crate) fn set(&mut self, key: &'static str, val: String) -> &mut Self {
+        set_param(&mut self.params, key, val);
+        self
+    }
+
+    /// The text to draw. Sent as `txt64` if it contains non-ASCII characters,
+    /// and as `txt` otherwise.
+    pub fn text(&mut self, val: &str) -> &mut Self {
+        self.params.retain(|(k, _)| *k != "txt" && *k != "txt64");
+
+        if val.is_ascii() {
+            self.set("txt", val.into())
+        } else {
+            self.set("txt64", encoding::base64(val))
+        }
+    }
+
+    /// The font family and style of the text.
+    pub fn font(&mut self, family: ImgixFontFamily, style: ImgixFontStyle) -> &mut Self {
+        let val = match style {
+            ImgixFontStyle::Regular => family.to_string(),
+            _ => format!("{},{}", family, style),
+        };
+
+        self.set("txt-font", val)
+    }
+
+    /// The font size of the text, in pixels.
+    pub fn size(&mut self, val: i32) -> &mut Self {
+        self.set("txt-size", val.to_string())
+    }
+
+    /// The color of the text.
+    pub fn color(&mut self, color: ImgixColor) -> &mut Self {
+        self.set("txt-color", color.to_string())
+    }
+
+    /// The alignment of the text within the image.
+    pub fn align(&mut self, align: ImgixAlign) -> &mut Self {
+        self.set("txt-align", align.to_string())
+    }
+
+    /// The padding between the text and the edges of the image, in pixels.
+    pub fn pad(&mut self, val: i32) -> &mut Self {
+        self.set("txt-pad", val.to_string())
+    }
+
+    /// The strength of the drop shadow of the text. Valid values are in the
+    /// range of 0 - 10.
+    pub fn shad(&mut self, val: f64) -> &mut Self {
+        self.set("txt-shad", val.to_string())
+    }
+
+    /// How the text is clipped when it does not fit, optionally replacing the
+    /// clipped part with an ellipsis.
+    pub fn clip(&mut self, clip: ImgixTextClip, ellipsis: bool) -> &mut Self {
+        let val = if ellipsis {
+            format!("{},ellipsis", clip)
+        } else {
+            clip.to_string()
+        };
+
+        self.set("txt-clip", val)
+    }
+
+    /// Shrinks the font size so that the text fits within `width()`.
+    pub fn fit_max(&mut self) -> &mut Self {
+        self.set("txt-fit", "max".into())
+    }
+
+    /// The maximum width of the text, in pixels. Longer text is wrapped.
+    pub fn width(&mut self, val: i32) -> &mut Self {
+        self.set("txt-width", val.to_string())
+    }
+
+    /// The width of the outline drawn around the text, in pixels.
+    pub fn line(&mut self, val: i32) -> &mut Self {
+        self.set("txt-line", val.to_string())
+    }
+
+    /// The color of the outline drawn around the text.
+    pub fn line_color(&mut self, color: ImgixColor) -> &mut Self {
+        self.set("txt-line-color", color.to_string())
+    }
+
+    /// The leading (line spacing) of wrapped text, in pixels.
+    pub fn lead(&mut self, val: i32) -> &mut Self {
+        self.set("txt-lead", val.to_string())
+    }
+
+    /// The tracking (letter spacing) of the text, in pixels.
+    pub fn track(&mut self, val: i32) -> &mut Self {
+        self.set("txt-track", val.to_string())
+    }
+}
+
+/// Checks that a `txt-font` value names a known family in a style it is
+/// available in.
+pub(crate) fn check_font(val: &str) -> Result<(), ImgixError> {
+    let invalid = || ImgixError::invalid_value("txt-font", val);
+    let mut parts = val.split(',');
+    let family: ImgixFontFamily = parts.next().unwrap_or_default().parse()?;
+    let (mut bold, mut italic) = (false, false);
+
+    for part in parts {
+        match part {
+            "bold" if !bold => bold = true,
+            "italic" if !italic => italic = true,
+            _ => return Err(invalid()),
+        }
+    }
+
+    let style = match (bold, italic) {
+        (false, false) => ImgixFontStyle::Regular,
+        (true, false) => ImgixFontStyle::Bold,
+        (false, true) => ImgixFontStyle::Italic,
+        (true, true) => ImgixFontStyle::BoldItalic,
+    };
+
+    if family.supports(style) {
+        Ok(())
+    } else {
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let text = ImgixText::build()
+            .text("Hello")
+            .text("Grüße")
+            .clip(ImgixTextClip::End, true)
+            .finish();
+
+        assert_eq!(
+            text.params(),
+            &[
+                ("txt64", "R3LDvMOfZQ".to_string()),
+                ("txt-clip", "end,ellipsis".to_string())
+            ]
+        );
+        assert!(check_font("Futura,italic").is_ok());
+        assert!(check_font("Papyrus,bold").is_err());
+        assert!(check_font("Comic Sans").is_err());
+        assert_eq!("bold,italic".parse(), Ok(ImgixFontStyle::BoldItalic));
+        assert_eq!("middle".parse(), Ok(ImgixTextClip::Middle));
+    }
+}
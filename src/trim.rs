@@ -0,0 +1,94 @@
+use crate::{ImgixColor, ImgixNumber};
+
+/// The `trim` parameter trims the uniform border around an image, either by
+/// detecting it automatically or by matching a color.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixColor, ImgixTrim, ImgixUrl};
+///
+/// let url = ImgixUrl::build("foo.imgix.net", "product.jpg")
+///     .trim(ImgixTrim::Color {
+///         color: ImgixColor::named("white").ok(),
+///         tol: Some(10.into()),
+///     })
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/product.jpg?trim=color&trim-color=white&trim-tol=10"
+/// );
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/trim) for more info.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixTrim {
+    /// Trims the border when the pixels around the edges of the image are
+    /// similar enough.
+    Auto {
+        /// The minimum mean difference between the edge pixels and the
+        /// surrounding area for the border to be trimmed. Defaults to 11.
+        md: Option<ImgixNumber>,
+
+        /// The maximum standard deviation of the edge pixels for the border
+        /// to be trimmed. Defaults to 10.
+        sd: Option<ImgixNumber>,
+    },
+
+    /// Trims the border matching a color.
+    Color {
+        /// The color to trim. Defaults to the color of the top left pixel.
+        color: Option<ImgixColor>,
+
+        /// How far pixels may differ from `color` and still be trimmed.
+        /// Defaults to 0.
+        tol: Option<ImgixNumber>,
+    },
+}
+
+impl ImgixTrim {
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        match self {
+            ImgixTrim::Auto { md, sd } => {
+                params.push(("trim", "auto".into()));
+                if let Some(md) = md {
+                    params.push(("trim-md", md.to_string()));
+                }
+                if let Some(sd) = sd {
+                    params.push(("trim-sd", sd.to_string()));
+                }
+            }
+            ImgixTrim::Color { color, tol } => {
+                params.push(("trim", "color".into()));
+                if let Some(color) = color {
+                    params.push(("trim-color", color.to_string()));
+                }
+                if let Some(tol) = tol {
+                    params.push(("trim-tol", tol.to_string()));
+                }
+            }
+        }
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(
+            ImgixTrim::Auto {
+                md: None,
+                sd: Some(5.5.into())
+            }
+            .params(),
+            vec![("trim", "auto".to_string()), ("trim-sd", "5.5".to_string())]
+        );
+    }
+}
@@ -0,0 +1,553 @@
+use crate::{error::ImgixError, mask, text, ImgixBlendMode, ImgixColor, ImgixRadius, ImgixWarning};
+
+/// Inclusive ranges of numeric parameters, as documented by Imgix.
+const RANGES: &[(&str, f64, f64)] = &[
+    ("q", 0.0, 100.0),
+    ("min-w", 1.0, f64::INFINITY),
+    ("min-h", 1.0, f64::INFINITY),
+    ("max-w", 1.0, f64::INFINITY),
+    ("max-h", 1.0, f64::INFINITY),
+    ("dpr", 1.0, 5.0),
+    ("blur", 0.0, 2000.0),
+    ("faceindex", 1.0, f64::INFINITY),
+    ("facepad", 1.0, f64::INFINITY),
+    ("colorquant", 2.0, 256.0),
+    ("dpi", 1.0, f64::INFINITY),
+    ("bri", -100.0, 100.0),
+    ("con", -100.0, 100.0),
+    ("exp", -100.0, 100.0),
+    ("gam", -100.0, 100.0),
+    ("high", -100.0, 0.0),
+    ("hue", -359.0, 359.0),
+    ("nr", -100.0, 100.0),
+    ("nrs", -100.0, 100.0),
+    ("sat", -100.0, 100.0),
+    ("shad", 0.0, 100.0),
+    ("sharp", 0.0, 100.0),
+    ("usm", -100.0, 100.0),
+    ("usmrad", 0.0, f64::INFINITY),
+    ("vib", -100.0, 100.0),
+    ("duotone-alpha", 0.0, 100.0),
+    ("htn", 0.0, 100.0),
+    ("px", 0.0, 100.0),
+    ("sepia", 0.0, 100.0),
+    ("txt-size", 1.0, f64::INFINITY),
+    ("txt-pad", 0.0, f64::INFINITY),
+    ("txt-shad", 0.0, 10.0),
+    ("txt-width", 1.0, f64::INFINITY),
+    ("txt-line", 0.0, f64::INFINITY),
+    ("txt-lead", 0.0, f64::INFINITY),
+    ("mark-alpha", 0.0, 100.0),
+    ("mark-pad", 0.0, f64::INFINITY),
+    ("mark-scale", 0.0, 100.0),
+    ("mark-w", 0.0, f64::INFINITY),
+    ("mark-h", 0.0, f64::INFINITY),
+    ("blend-alpha", 0.0, 100.0),
+    ("blend-pad", 0.0, f64::INFINITY),
+    ("blend-w", 0.0, f64::INFINITY),
+    ("blend-h", 0.0, f64::INFINITY),
+    ("pad", 0.0, f64::INFINITY),
+    ("pad-top", 0.0, f64::INFINITY),
+    ("pad-right", 0.0, f64::INFINITY),
+    ("pad-bottom", 0.0, f64::INFINITY),
+    ("pad-left", 0.0, f64::INFINITY),
+    ("border-top", 0.0, f64::INFINITY),
+    ("border-right", 0.0, f64::INFINITY),
+    ("border-bottom", 0.0, f64::INFINITY),
+    ("border-left", 0.0, f64::INFINITY),
+    ("fp-x", 0.0, 1.0),
+    ("fp-y", 0.0, 1.0),
+    ("fp-z", 1.0, 100.0),
+    ("rot", 0.0, 359.0),
+    ("trim-md", 0.0, f64::INFINITY),
+    ("trim-sd", 0.0, f64::INFINITY),
+    ("trim-tol", 0.0, f64::INFINITY),
+];
+
+/// Parameters that only take effect when another parameter is set to one of
+/// the given values, or set at all if no values are given. Comma separated
+/// values satisfy the rule if any of them match.
+const REQUIRES: &[(&str, &str, &[&str])] = &[
+    ("ar", "fit", &["crop"]),
+    ("crop", "fit", &["crop"]),
+    ("faceindex", "fit", &["facearea"]),
+    ("facepad", "fit", &["facearea"]),
+    ("lossless", "fm", &["webp", "jxr", "avif"]),
+    ("chromasub", "fm", &["jpg", "pjpg"]),
+    ("colorquant", "fm", &["gif", "png8"]),
+    ("dpi", "fm", &["jpg", "pjpg", "png", "png8", "png32"]),
+    ("duotone-alpha", "duotone", &[]),
+    ("border-top", "border", &[]),
+    ("border-right", "border", &[]),
+    ("border-bottom", "border", &[]),
+    ("border-left", "border", &[]),
+    ("border-radius", "border", &[]),
+    ("border-radius-inner", "border", &[]),
+    ("corner-radius", "mask", &["corners"]),
+    ("mask-bg", "mask", &[]),
+    ("fp-x", "fit", &["crop"]),
+    ("fp-x", "crop", &["focalpoint"]),
+    ("fp-y", "fit", &["crop"]),
+    ("fp-y", "crop", &["focalpoint"]),
+    ("fp-z", "fit", &["crop"]),
+    ("fp-z", "crop", &["focalpoint"]),
+    ("fp-debug", "fit", &["crop"]),
+    ("fp-debug", "crop", &["focalpoint"]),
+    ("trim-md", "trim", &["auto"]),
+    ("trim-sd", "trim", &["auto"]),
+    ("trim-color", "trim", &["color"]),
+    ("trim-tol", "trim", &["color"]),
+    ("fill", "fit", &["fill", "fillmax"]),
+    ("fill-color", "fit", &["fill", "fillmax"]),
+    ("fill-color", "fill", &["solid"]),
+    ("fill-gen-prompt", "fill", &["gen"]),
+    ("fill-gen-seed", "fill", &["gen"]),
+    ("fill-gen-fallback", "fill", &["gen"]),
+];
+
+/// Parameters sharing a prefix that only take effect when one of the given
+/// parameters is set.
+const PREFIXES: &[(&str, &[&str])] = &[
+    ("txt-", &["txt", "txt64"]),
+    ("mark-", &["mark", "mark64"]),
+    ("blend-", &["blend", "blend64"]),
+];
+
+/// Parameters whose comma separated values must each be one of a fixed set.
+const VALUES: &[(&str, &[&str])] = &[
+    ("mark-fit", &["clip", "crop", "fill", "max", "scale"]),
+    ("mark-tile", &["grid"]),
+    ("blend-fit", &["clamp", "clip", "crop", "max", "scale"]),
+    ("blend-crop", &["top", "bottom", "left", "right", "faces"]),
+    ("blend-size", &["inherit"]),
+    (
+        "orient",
+        &[
+            "0", "1", "2", "3", "4", "5", "6", "7", "8", "90", "180", "270",
+        ],
+    ),
+    ("flip", &["h", "v", "hv"]),
+    ("trim", &["auto", "color"]),
+    ("fill", &["solid", "blur", "gen"]),
+    ("fill-gen-fallback", &["true", "false"]),
+];
+
+/// Parameters that take a color. `duotone` takes two, and `blend` also takes
+/// an image URL.
+pub(crate) const COLORS: &[&str] = &[
+    "bg",
+    "blend",
+    "duotone",
+    "fill-color",
+    "mask-bg",
+    "monochrome",
+    "trim-color",
+    "txt-color",
+    "txt-line-color",
+];
+
+/// Size limits, which only take effect with the given `fit` modes.
+const LIMITS: &[&str] = &["min-w", "min-h", "max-w", "max-h"];
+
+/// The `fit` modes that use the size limits.
+const LIMIT_FITS: &[&str] = &["crop", "min", "max"];
+
+/// Alignment parameters, which take at most one vertical and one horizontal
+/// value.
+const ALIGNS: &[&str] = &["txt-align", "mark-align", "blend-align"];
+
+/// Values of comma separated parameters that cannot be used together.
+const CONFLICTS: &[(&[&str], &str, &str)] = &[
+    (&["crop", "blend-crop"], "top", "bottom"),
+    (&["crop", "blend-crop"], "left", "right"),
+    (ALIGNS, "top", "middle"),
+    (ALIGNS, "top", "bottom"),
+    (ALIGNS, "middle", "bottom"),
+    (ALIGNS, "left", "center"),
+    (ALIGNS, "left", "right"),
+    (ALIGNS, "center", "right"),
+];
+
+/// Checks a list of parameters against the rules of Imgix's URL API, returning
+/// the first violation found.
+pub(crate) fn validate<K: AsRef<str>>(params: &[(K, String)]) -> Result<(), ImgixError> {
+    let get = |key: &str| find(params, key);
+
+    for &(key, min, max) in RANGES {
+        if let Some(val) = get(key) {
+            check_range(key, val, min, max)?;
+        }
+    }
+
+    for &(key, values) in VALUES {
+        if let Some(val) = get(key) {
+            if !val.split(',').all(|opt| values.contains(&opt)) {
+                return Err(ImgixError::invalid_value(key, val));
+            }
+        }
+    }
+
+    for &(key, dep, values) in REQUIRES {
+        if get(key).is_none() {
+            continue;
+        }
+
+        let satisfied = match get(dep) {
+            Some(val) => values.is_empty() || val.split(',').any(|opt| values.contains(&opt)),
+            None => false,
+        };
+
+        if !satisfied {
+            let requires = if values.is_empty() {
+                dep.to_string()
+            } else {
+                values
+                    .iter()
+                    .map(|v| format!("{}={}", dep, v))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            };
+
+            return Err(ImgixError::MissingParam {
+                param: key.into(),
+                requires,
+            });
+        }
+    }
+
+    for &(prefix, deps) in PREFIXES {
+        let prefixed = params
+            .iter()
+            .map(|(k, _)| k.as_ref())
+            .find(|k| k.starts_with(prefix));
+
+        if let Some(key) = prefixed {
+            if deps.iter().all(|dep| get(dep).is_none()) {
+                return Err(ImgixError::MissingParam {
+                    param: key.into(),
+                    requires: deps.join(" or "),
+                });
+            }
+        }
+    }
+
+    for &(keys, first, second) in CONFLICTS {
+        for &key in keys {
+            if let Some(val) = get(key) {
+                let opts: Vec<&str> = val.split(',').collect();
+
+                if opts.contains(&first) && opts.contains(&second) {
+                    return Err(ImgixError::Conflict {
+                        param: key.into(),
+                        first: first.into(),
+                        second: second.into(),
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(val) = get("mask") {
+        mask::check(val)?;
+    }
+
+    if let Some(val) = get("txt-font") {
+        text::check_font(val)?;
+    }
+
+    if let Some(val) = get("border") {
+        let (width, color) = val
+            .split_once(',')
+            .ok_or_else(|| ImgixError::invalid_value("border", val))?;
+
+        check_range("border", width, 0.0, f64::INFINITY)?;
+        color.parse::<ImgixColor>()?;
+    }
+
+    for key in &["border-radius", "border-radius-inner", "corner-radius"] {
+        if let Some(val) = get(key) {
+            let radius: ImgixRadius = val.parse()?;
+
+            if radius.corners().iter().any(|r| *r < 0) {
+                return Err(ImgixError::OutOfRange {
+                    param: key.to_string(),
+                    value: val.into(),
+                    expected: "of at least 0".into(),
+                });
+            }
+        }
+    }
+
+    for &key in COLORS {
+        if let Some(val) = get(key) {
+            check_color(key, val)?;
+        }
+    }
+
+    if let Some(val) = get("blend-mode") {
+        val.parse::<ImgixBlendMode>()?;
+    }
+
+    if get("crop").is_some() && get("w").is_none() && get("h").is_none() {
+        return Err(ImgixError::MissingParam {
+            param: "crop".into(),
+            requires: "w or h".into(),
+        });
+    }
+
+    for &(min_key, max_key) in &[("min-w", "max-w"), ("min-h", "max-h")] {
+        if let (Some(min), Some(max)) = (get(min_key), get(max_key)) {
+            check_range(min_key, min, 1.0, max.parse().unwrap_or(f64::INFINITY))?;
+        }
+    }
+
+    for &key in &["w", "h"] {
+        if let Some(val) = get(key) {
+            let num: f64 = val
+                .parse()
+                .map_err(|_| ImgixError::invalid_value(key, val))?;
+
+            if num <= 0.0 || num.is_infinite() {
+                return Err(ImgixError::OutOfRange {
+                    param: key.into(),
+                    value: val.into(),
+                    expected: "greater than 0".into(),
+                });
+            }
+        }
+    }
+
+    if let Some(val) = get("ar") {
+        let positive = val
+            .split(':')
+            .all(|side| side.parse::<f64>().is_ok_and(|n| n > 0.0));
+
+        if !positive {
+            return Err(ImgixError::OutOfRange {
+                param: "ar".into(),
+                value: val.into(),
+                expected: "with both sides greater than 0".into(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a list of parameters for combinations that Imgix accepts, but that
+/// are likely to give unexpected results.
+pub(crate) fn warnings<K: AsRef<str>>(params: &[(K, String)]) -> Vec<ImgixWarning> {
+    let get = |key: &str| find(params, key);
+    let mut warnings = Vec::new();
+
+    if let Some(rot) = get("rot") {
+        let right_angle = rot.parse::<f64>().is_ok_and(|n| n % 90.0 == 0.0);
+
+        if !right_angle && get("bg").is_none() {
+            warnings.push(ImgixWarning::ExposedCorners { rot: rot.into() });
+        }
+    }
+
+    let fit = get("fit").unwrap_or("clip");
+
+    if !LIMIT_FITS.contains(&fit) {
+        for &key in LIMITS {
+            if get(key).is_some() {
+                warnings.push(ImgixWarning::IgnoredByFit {
+                    param: key.into(),
+                    fit: fit.into(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Returns the value of the parameter named `key`, if it is set.
+fn find<'a, K: AsRef<str>>(params: &'a [(K, String)], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k.as_ref() == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Checks the value of one of the `COLORS` parameters.
+pub(crate) fn check_color(key: &str, val: &str) -> Result<(), ImgixError> {
+    let invalid = || ImgixError::invalid_value(key, val);
+    let color = |s: &str| s.parse::<ImgixColor>().map(drop).map_err(|_| invalid());
+
+    match key {
+        "duotone" => {
+            let (shadow, highlight) = val.split_once(',').ok_or_else(invalid)?;
+            color(shadow)?;
+            color(highlight)
+        }
+        "blend" if val.starts_with("http://") || val.starts_with("https://") => Ok(()),
+        _ => color(val),
+    }
+}
+
+/// Checks that `val` is a number between `min` and `max` inclusive.
+fn check_range(key: &str, val: &str, min: f64, max: f64) -> Result<(), ImgixError> {
+    let num: f64 = val
+        .parse()
+        .map_err(|_| ImgixError::invalid_value(key, val))?;
+
+    if num >= min && num <= max {
+        return Ok(());
+    }
+
+    let expected = if max.is_infinite() {
+        format!("of at least {}", min)
+    } else {
+        format!("between {} and {}", min, max)
+    };
+
+    Err(ImgixError::OutOfRange {
+        param: key.into(),
+        value: val.into(),
+        expected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        let params = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        assert!(validate(&params(&[("fit", "crop"), ("ar", "16:9")])).is_ok());
+        assert_eq!(
+            validate(&params(&[("q", "400")])).unwrap_err().to_string(),
+            "`400` is out of range for `q`, expected a value between 0 and 100"
+        );
+        assert_eq!(
+            validate(&params(&[("ar", "16:9")]))
+                .unwrap_err()
+                .to_string(),
+            "`ar` requires fit=crop to be set"
+        );
+        assert_eq!(
+            validate(&params(&[
+                ("fit", "crop"),
+                ("w", "9"),
+                ("crop", "top,bottom")
+            ]))
+            .unwrap_err()
+            .to_string(),
+            "`crop` cannot combine `top` and `bottom`"
+        );
+        assert_eq!(
+            validate(&params(&[("fit", "crop"), ("crop", "top")]))
+                .unwrap_err()
+                .to_string(),
+            "`crop` requires w or h to be set"
+        );
+        assert_eq!(
+            validate(&params(&[("fm", "png"), ("colorquant", "8")]))
+                .unwrap_err()
+                .to_string(),
+            "`colorquant` requires fm=gif or fm=png8 to be set"
+        );
+        assert_eq!(
+            validate(&params(&[("txt-size", "12")]))
+                .unwrap_err()
+                .to_string(),
+            "`txt-size` requires txt or txt64 to be set"
+        );
+        assert_eq!(
+            validate(&params(&[("txt", "Hi"), ("txt-align", "left,top,right")]))
+                .unwrap_err()
+                .to_string(),
+            "`txt-align` cannot combine `left` and `right`"
+        );
+        assert_eq!(
+            validate(&params(&[("mark64", "Zm9v"), ("mark-fit", "facearea")]))
+                .unwrap_err()
+                .to_string(),
+            "`facearea` is not a valid value for `mark-fit`"
+        );
+        assert!(validate(&params(&[("w", "0.5")])).is_ok());
+        assert_eq!(
+            validate(&params(&[("bg", "zzz")])).unwrap_err().to_string(),
+            "`zzz` is not a valid value for `bg`"
+        );
+        assert!(validate(&params(&[("blend", "https://foo.com/a.png")])).is_ok());
+        assert_eq!(
+            validate(&params(&[("w", "0")])).unwrap_err().to_string(),
+            "`0` is out of range for `w`, expected a value greater than 0"
+        );
+        assert_eq!(
+            validate(&params(&[("blend-alpha", "50")]))
+                .unwrap_err()
+                .to_string(),
+            "`blend-alpha` requires blend or blend64 to be set"
+        );
+        assert_eq!(
+            validate(&params(&[("border", "-1,fff")]))
+                .unwrap_err()
+                .to_string(),
+            "`-1` is out of range for `border`, expected a value of at least 0"
+        );
+        assert_eq!(
+            validate(&params(&[("mask", "ellipse"), ("corner-radius", "8")]))
+                .unwrap_err()
+                .to_string(),
+            "`corner-radius` requires mask=corners to be set"
+        );
+        assert_eq!(
+            validate(&params(&[
+                ("w", "300"),
+                ("fit", "crop"),
+                ("crop", "faces"),
+                ("fp-x", "0.5")
+            ]))
+            .unwrap_err()
+            .to_string(),
+            "`fp-x` requires crop=focalpoint to be set"
+        );
+        assert_eq!(
+            validate(&params(&[("trim", "auto"), ("trim-tol", "5")]))
+                .unwrap_err()
+                .to_string(),
+            "`trim-tol` requires trim=color to be set"
+        );
+        assert_eq!(
+            validate(&params(&[
+                ("fit", "fill"),
+                ("fill", "blur"),
+                ("fill-color", "fff")
+            ]))
+            .unwrap_err()
+            .to_string(),
+            "`fill-color` requires fill=solid to be set"
+        );
+        assert_eq!(
+            warnings(&params(&[("rot", "45")])),
+            vec![ImgixWarning::ExposedCorners { rot: "45".into() }]
+        );
+        assert!(warnings(&params(&[("rot", "45"), ("bg", "fff")])).is_empty());
+        assert_eq!(
+            validate(&params(&[("min-w", "500"), ("max-w", "300")]))
+                .unwrap_err()
+                .to_string(),
+            "`500` is out of range for `min-w`, expected a value between 1 and 300"
+        );
+        assert_eq!(
+            warnings(&params(&[("max-w", "300")])),
+            vec![ImgixWarning::IgnoredByFit {
+                param: "max-w".into(),
+                fit: "clip".into()
+            }]
+        );
+        assert!(warnings(&params(&[("fit", "max"), ("max-w", "300")])).is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// Parameter combinations that Imgix accepts, but that are likely to give
+/// unexpected results. Returned by `ImgixUrlBuilder::warnings()`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImgixWarning {
+    /// `rot` is not a multiple of 90, which exposes the corners of the
+    /// canvas, and `bg` is not set to control their color.
+    ExposedCorners {
+        /// The value of `rot`.
+        rot: String,
+    },
+
+    /// A parameter is set that has no effect with the current `fit` mode.
+    /// When `fit` is not set, Imgix's default of `clip` is assumed.
+    IgnoredByFit {
+        /// Name of the parameter.
+        param: String,
+
+        /// The `fit` mode in effect.
+        fit: String,
+    },
+}
+
+impl fmt::Display for ImgixWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImgixWarning::ExposedCorners { rot } => write!(
+                f,
+                "`rot={}` exposes the corners of the image, set `bg` to control their color",
+                rot
+            ),
+            ImgixWarning::IgnoredByFit { param, fit } => {
+                write!(f, "`{}` has no effect with fit={}", param, fit)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn works() {
+        assert_eq!(
+            ImgixWarning::ExposedCorners { rot: "45".into() }.to_string(),
+            "`rot=45` exposes the corners of the image, set `bg` to control their color"
+        );
+    }
+}
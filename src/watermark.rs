@@ -0,0 +1,158 @@
+use crate::{encoding, set_param, ImgixAlign, ImgixFit, ImgixUrlBuilder};
+
+/// Builder to represent the `mark` parameter and the `mark-*` parameters that
+/// position it. Begin constructing the parameters by calling `build()`.
+///
+/// The watermark image can be a plain URL or another `ImgixUrlBuilder`, which
+/// lets the watermark have its own parameters (and signature, if it has a
+/// secure token). Either way, it is sent base64 encoded with `mark64`.
+///
+/// # Example
+/// ```
+/// use rs_imgix::{ImgixAlign, ImgixFormat, ImgixUrl, ImgixWatermark};
+///
+/// let logo = ImgixUrl::build("foo.imgix.net", "logo.png")
+///     .w(100)
+///     .fm(ImgixFormat::Png)
+///     .clone();
+/// let url = ImgixUrl::build("foo.imgix.net", "cat.jpg")
+///     .mark(
+///         ImgixWatermark::build()
+///             .image(&logo)
+///             .align(ImgixAlign::build().bottom().right().finish())
+///             .alpha(80)
+///             .finish(),
+///     )
+///     .finish();
+///
+/// assert_eq!(
+///     url,
+///     "https://foo.imgix.net/cat.jpg\
+///      ?mark64=aHR0cHM6Ly9mb28uaW1naXgubmV0L2xvZ28ucG5nP3c9MTAwJmZtPXBuZw\
+///      &mark-align=bottom,right&mark-alpha=80"
+/// );
+/// ```
+///
+/// See [Imgix docs](https://docs.imgix.com/apis/url/watermark) for more info.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ImgixWatermark {
+    params: Vec<(&'static str, String)>,
+}
+
+impl ImgixWatermark {
+    /// Starts building the watermark parameters. Returns an `ImgixWatermark`
+    /// to specify the watermark image and its placement.
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    /// Completes the construction of the watermark parameters and returns the
+    /// final `ImgixWatermark` type.
+    pub fn finish(&self) -> Self {
+        self.to_owned()
+    }
+
+    /// Returns the parameters to set on the URL.
+    pub(crate) fn params(&self) -> &[(&'static str, String)] {
+        &self.params
+    }
+
+    pub(crate) fn set(&mut self, key: &'static str, val: String) -> &mut Self {
+        set_param(&mut self.params, key, val);
+        self
+    }
+
+    /// Uses the image at `url` as the watermark.
+    pub fn url(&mut self, url: &str) -> &mut Self {
+        self.set("mark64", encoding::base64(url))
+    }
+
+    /// Uses the Imgix image built by `image` as the watermark. The nested URL
+    /// is signed if `image` has a secure token.
+    pub fn image(&mut self, image: &ImgixUrlBuilder) -> &mut Self {
+        self.url(&image.finish())
+    }
+
+    /// The alignment of the watermark within the image.
+    pub fn align(&mut self, align: ImgixAlign) -> &mut Self {
+        self.set("mark-align", align.to_string())
+    }
+
+    /// The opacity of the watermark. Valid values are in the range of 0 - 100.
+    pub fn alpha(&mut self, val: i32) -> &mut Self {
+        self.set("mark-alpha", val.to_string())
+    }
+
+    /// The padding between the watermark and the edges of the image, in
+    /// pixels.
+    pub fn pad(&mut self, val: i32) -> &mut Self {
+        self.set("mark-pad", val.to_string())
+    }
+
+    /// The width of the watermark as a percentage of the image width. Valid
+    /// values are in the range of 0 - 100.
+    pub fn scale(&mut self, val: i32) -> &mut Self {
+        self.set("mark-scale", val.to_string())
+    }
+
+    /// How the watermark is fit to `w()` and `h()`. Only `Clip`, `Crop`,
+    /// `Fill`, `Max` and `Scale` are valid.
+    pub fn fit(&mut self, fit: ImgixFit) -> &mut Self {
+        self.set("mark-fit", fit.to_string())
+    }
+
+    /// The width of the watermark, in pixels.
+    pub fn w(&mut self, val: i32) -> &mut Self {
+        self.set("mark-w", val.to_string())
+    }
+
+    /// The height of the watermark, in pixels.
+    pub fn h(&mut self, val: i32) -> &mut Self {
+        self.set("mark-h", val.to_string())
+    }
+
+    /// The horizontal position of the watermark, in pixels. Overrides the
+    /// horizontal part of `align()`.
+    pub fn x(&mut self, val: i32) -> &mut Self {
+        self.set("mark-x", val.to_string())
+    }
+
+    /// The vertical position of the watermark, in pixels. Overrides the
+    /// vertical part of `align()`.
+    pub fn y(&mut self, val: i32) -> &mut Self {
+        self.set("mark-y", val.to_string())
+    }
+
+    /// Repeats the watermark in a grid across the whole image.
+    pub fn tile(&mut self) -> &mut Self {
+        self.set("mark-tile", "grid".into())
+    }
+
+    /// A base URL that relative watermark paths are resolved against.
+    pub fn base(&mut self, url: &str) -> &mut Self {
+        self.set("mark-base", url.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ImgixUrl;
+
+    #[test]
+    fn works() {
+        let logo = ImgixUrl::build("foo.com", "logo.png")
+            .secure_token("FOO123bar")
+            .clone();
+        let mark = ImgixWatermark::build().image(&logo).tile().finish();
+
+        assert_eq!(
+            mark.params(),
+            &[
+                ("mark64", encoding::base64(&logo.finish())),
+                ("mark-tile", "grid".to_string())
+            ]
+        );
+        assert!(logo.finish().contains("?s="));
+    }
+}